@@ -1,16 +1,29 @@
 use window::*;
 
 fn main() {
-    let mut window = create_window("Window", 0, 0, 50, 50, WindowStyle::BORDERLESS);
+    let mut window = create_window(
+        "Window",
+        None,
+        LogicalSize::new(400, 300),
+        WindowStyle::BORDERLESS,
+    );
+
+    //Drag the top of the window to move it and the edges to resize it.
+    window.set_custom_frame(true);
 
     loop {
         match window.event() {
-            Some(Event::Quit | Event::Input(Key::Escape, _)) => break,
+            Some(Event::Quit | Event::CloseRequested | Event::Input(Key::Escape, _)) => break,
             Some(Event::Input(key, modifiers)) => println!("{:?} {:?}", key, modifiers),
             _ => {}
         }
 
+        let title_bar = Rect::new(0, 0, window.buffer_size().width as usize, 30);
+        window.set_hit_regions(vec![(title_bar, HitTest::Caption)]);
+
         window.buffer.fill(0x4fa3a8);
+        let len = (title_bar.width * title_bar.height).min(window.buffer.len());
+        window.buffer[..len].fill(0x165d6a);
         window.draw();
     }
 }
@@ -0,0 +1,36 @@
+use window::*;
+
+fn main() {
+    let mut window = create_window(
+        "Drop files here",
+        None,
+        LogicalSize::new(600, 400),
+        WindowStyle::DEFAULT,
+    );
+    window.accept_files();
+
+    let mut hovering = false;
+
+    loop {
+        match window.event_blocking() {
+            Some(Event::Quit | Event::CloseRequested | Event::Input(Key::Escape, _)) => break,
+            Some(Event::FileHovered(path)) => {
+                println!("Hovering {} at {:?}", path.display(), window.drop_position);
+                hovering = true;
+            }
+            Some(Event::FileDropped(paths)) => {
+                for path in paths {
+                    println!("Dropped {}", path.display());
+                }
+                hovering = false;
+            }
+            Some(Event::HoverCancelled) => hovering = false,
+            _ => {}
+        }
+
+        window
+            .buffer
+            .fill(if hovering { 0x165d6a } else { 0x4fa3a8 });
+        window.draw();
+    }
+}
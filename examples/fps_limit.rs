@@ -8,13 +8,18 @@ fn main() {
 
 #[allow(unused)]
 fn dwm_flush() {
-    let window = create_window("Window", 0, 0, 600, 400, WindowStyle::DEFAULT);
+    let window = create_window(
+        "Window",
+        None,
+        LogicalSize::new(600, 400),
+        WindowStyle::DEFAULT,
+    );
     let mut frame_counter = 0;
     let mut last_time = Instant::now();
 
     loop {
         match window.event() {
-            Some(Event::Quit | Event::Input(Key::Escape, _)) => break,
+            Some(Event::Quit | Event::CloseRequested | Event::Input(Key::Escape, _)) => break,
             Some(Event::Input(key, modifiers)) => println!("{:?} {:?}", key, modifiers),
             _ => {}
         }
@@ -38,15 +43,23 @@ fn dwm_flush() {
 #[allow(unused)]
 fn wait_timer() {
     unsafe {
-        let window = create_window("Window", 0, 0, 600, 400, WindowStyle::DEFAULT);
+        let window = create_window(
+            "Window",
+            None,
+            LogicalSize::new(600, 400),
+            WindowStyle::DEFAULT,
+        );
 
         assert!(timeBeginPeriod(1) == 0);
 
         let timer = CreateWaitableTimerA(std::ptr::null_mut(), 1, std::ptr::null());
         assert!(!timer.is_null());
 
-        //TODO: Dynamically get the refresh rate.
-        let refresh_rate = 60.0;
+        //0 and 1 mean the hardware default.
+        let refresh_rate = match window.current_monitor().map(|monitor| monitor.refresh_rate) {
+            None | Some(0 | 1) => 60.0,
+            Some(hz) => hz as f64,
+        };
 
         //Measured in 100 nanosecond intervals.
         //Negative because relative.
@@ -57,7 +70,7 @@ fn wait_timer() {
 
         loop {
             match window.event() {
-                Some(Event::Quit | Event::Input(Key::Escape, _)) => break,
+                Some(Event::Quit | Event::CloseRequested | Event::Input(Key::Escape, _)) => break,
                 Some(Event::Input(key, modifiers)) => println!("{:?} {:?}", key, modifiers),
                 _ => {}
             }
@@ -0,0 +1,27 @@
+use window::*;
+
+fn main() {
+    let mut window = create_window(
+        "Fullscreen",
+        None,
+        LogicalSize::new(600, 400),
+        WindowStyle::DEFAULT,
+    );
+
+    loop {
+        match window.event() {
+            Some(Event::Quit | Event::CloseRequested | Event::Input(Key::Escape, _)) => break,
+            Some(Event::Input(Key::Function(11), _)) => {
+                if window.fullscreen.is_some() {
+                    window.set_fullscreen(None);
+                } else {
+                    window.set_fullscreen(Some(Fullscreen::Borderless));
+                }
+            }
+            _ => {}
+        }
+
+        window.buffer.fill(0x4fa3a8);
+        window.draw();
+    }
+}
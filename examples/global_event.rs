@@ -1,7 +1,12 @@
 use window::*;
 
 fn main() {
-    let window = create_window("test", 0, 0, 800, 500, WindowStyle::DEFAULT);
+    let window = create_window(
+        "test",
+        None,
+        LogicalSize::new(800, 500),
+        WindowStyle::DEFAULT,
+    );
 
     // Blocking message
 
@@ -15,7 +20,7 @@ fn main() {
 
     loop {
         let event = window.event();
-        if event == Some(Event::Quit) {
+        if matches!(event, Some(Event::Quit | Event::CloseRequested)) {
             return;
         }
 
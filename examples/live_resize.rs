@@ -0,0 +1,36 @@
+use window::*;
+
+//Checkerboard that follows the window size, so stretched or stale frames are easy to spot.
+fn render(window: &mut Window) {
+    let width = window.buffer_size().width as usize;
+    for (i, pixel) in window.buffer.iter_mut().enumerate() {
+        let (x, y) = (i % width, i / width);
+        *pixel = if (x / 32 + y / 32) % 2 == 0 {
+            0x4fa3a8
+        } else {
+            0x165d6a
+        };
+    }
+}
+
+fn main() {
+    let mut window = create_window(
+        "Window",
+        None,
+        LogicalSize::new(600, 400),
+        WindowStyle::DEFAULT,
+    );
+
+    //Keeps drawing while the edges are being dragged.
+    window.set_frame_callback(render);
+
+    loop {
+        match window.event() {
+            Some(Event::Quit | Event::CloseRequested | Event::Input(Key::Escape, _)) => break,
+            _ => {}
+        }
+
+        render(&mut window);
+        window.draw();
+    }
+}
@@ -1,33 +1,52 @@
 use window::*;
 
 fn main() {
-    let mut window = create_window("Window",  0, 0, 600, 400, WindowStyle::DEFAULT);
-    let mut window2 = create_window("Window2", 0, 0,  50, 50, WindowStyle::BORDERLESS);
+    let mut event_loop = EventLoop::new();
+    let main = event_loop.create_window(
+        "Window",
+        None,
+        LogicalSize::new(600, 400),
+        WindowStyle::DEFAULT,
+    );
+    let palette = event_loop.create_window(
+        "Window2",
+        None,
+        LogicalSize::new(50, 50),
+        WindowStyle::BORDERLESS,
+    );
+
     let mut point = POINT::default();
     unsafe { GetCursorPos(&mut point) };
-    let width = window2.width();
-    let height = window2.height();
+    let window2 = event_loop.window_mut(palette).unwrap();
     window2.set_pos(
-        point.x as usize,
-        point.y as usize,
-        width,
-        height,
-        SWP_FRAMECHANGED,
+        point.into(),
+        PhysicalSize::default(),
+        SWP_NOSIZE | SWP_FRAMECHANGED,
     );
 
     loop {
-        //Events need to be polled.
-        let _ = window2.event();
-
-        match window.event() {
-            Some(Event::Quit | Event::Input(Key::Escape, _)) => break,
-            Some(Event::Input(key, modifiers)) => println!("{:?} {:?}", key, modifiers),
-            _ => {}
+        //Every window is pumped at once.
+        while let Some((id, event)) = event_loop.event() {
+            match event {
+                Event::CloseRequested | Event::Input(Key::Escape, _) if id == main => return,
+                Event::Input(key, modifiers) => println!("{:?} {:?} {:?}", id, key, modifiers),
+                Event::MonitorsChanged(monitors) => {
+                    for monitor in monitors {
+                        println!(
+                            "{} {:?} {}hz",
+                            monitor.name, monitor.rect, monitor.refresh_rate
+                        );
+                    }
+                }
+                _ => {}
+            }
         }
 
+        let window = event_loop.window_mut(main).unwrap();
         window.buffer.fill(0x4fa3a8);
         window.draw();
 
+        let window2 = event_loop.window_mut(palette).unwrap();
         window2.buffer.fill(0x165d6a);
         window2.draw();
     }
@@ -32,7 +32,12 @@ pub fn lerp_hex(color1: u32, color2: u32, t: f32) -> u32 {
 }
 
 fn main() {
-    let mut window = create_window("Window", 0, 0, 600, 400, WindowStyle::DEFAULT);
+    let mut window = create_window(
+        "Window",
+        None,
+        LogicalSize::new(600, 400),
+        WindowStyle::DEFAULT,
+    );
     let hwnd = window.hwnd.clone();
 
     //TODO: I want each window to have it's own framebuffer.
@@ -88,7 +93,7 @@ fn main() {
 
     loop {
         match window.event() {
-            Some(Event::Quit | Event::Input(Key::Escape, _)) => break,
+            Some(Event::Quit | Event::CloseRequested | Event::Input(Key::Escape, _)) => break,
             Some(Event::Input(key, _)) => println!("{:?}", key),
             _ => {}
         }
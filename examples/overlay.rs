@@ -0,0 +1,23 @@
+use window::*;
+
+fn main() {
+    let mut window = create_window(
+        "Overlay",
+        None,
+        LogicalSize::new(300, 200),
+        WindowStyle::OVERLAY,
+    );
+
+    loop {
+        match window.event() {
+            Some(Event::Quit | Event::CloseRequested | Event::Input(Key::Escape, _)) => break,
+            _ => {}
+        }
+
+        //The buffer is 0xAARRGGBB for transparent windows.
+        window.buffer.fill(0x804fa3a8);
+        let top = (window.buffer_size().width as usize * 4).min(window.buffer.len());
+        window.buffer[..top].fill(0xFF165d6a);
+        window.draw();
+    }
+}
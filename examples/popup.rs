@@ -0,0 +1,47 @@
+use window::*;
+
+fn main() {
+    let mut event_loop = EventLoop::new();
+    let main = event_loop.create_window(
+        "Window",
+        None,
+        LogicalSize::new(600, 400),
+        WindowStyle::DEFAULT,
+    );
+    let mut menu: Option<WindowId> = None;
+
+    loop {
+        while let Some((id, event)) = event_loop.event() {
+            match event {
+                Event::CloseRequested | Event::Input(Key::Escape, _) if id == main => return,
+                //The popup closed itself after losing focus.
+                Event::Quit if Some(id) == menu => {
+                    event_loop.remove(id);
+                    menu = None;
+                }
+                _ => {}
+            }
+        }
+
+        let window = event_loop.window_mut(main).unwrap();
+        let size = window.buffer_size();
+        let area = Rect::new(0, 0, size.width as usize, size.height as usize);
+
+        //Right click opens a context menu at the cursor, it can hang off the edge of the window.
+        if window.right_mouse.clicked(area) && menu.is_none() {
+            let mut point = POINT::default();
+            unsafe { GetCursorPos(&mut point) };
+            let popup = create_popup(window, point.into(), LogicalSize::new(150, 200));
+            menu = Some(event_loop.add(popup));
+        }
+
+        let window = event_loop.window_mut(main).unwrap();
+        window.buffer.fill(0x4fa3a8);
+        window.draw();
+
+        if let Some(window) = menu.and_then(|id| event_loop.window_mut(id)) {
+            window.buffer.fill(0x165d6a);
+            window.draw();
+        }
+    }
+}
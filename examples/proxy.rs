@@ -0,0 +1,39 @@
+use std::{thread, time::Duration};
+use window::*;
+
+fn main() {
+    let mut window = create_window(
+        "Window",
+        None,
+        LogicalSize::new(600, 400),
+        WindowStyle::DEFAULT,
+    );
+
+    //Background work nudges the window instead of it busy polling.
+    let proxy = window.proxy();
+    thread::spawn(move || {
+        for progress in 1..=10 {
+            thread::sleep(Duration::from_millis(500));
+            if proxy.send(progress * 10).is_err() {
+                return;
+            }
+        }
+        proxy.wake();
+    });
+
+    loop {
+        match window.event_blocking() {
+            Some(Event::Quit | Event::CloseRequested | Event::Input(Key::Escape, _)) => break,
+            Some(Event::User(event)) => {
+                if let Some(progress) = event.downcast_ref::<i32>() {
+                    println!("{}%", progress);
+                }
+            }
+            Some(Event::Wake) => println!("Done"),
+            _ => {}
+        }
+
+        window.buffer.fill(0x4fa3a8);
+        window.draw();
+    }
+}
@@ -31,6 +31,7 @@ unsafe extern "system" {
     pub fn GlobalAlloc(flags: u32, bytes: usize) -> *mut c_void;
     pub fn GlobalLock(mem: *mut c_void) -> *mut c_void;
     pub fn GlobalUnlock(mem: *mut c_void) -> i32;
+    pub fn GlobalSize(mem: *mut c_void) -> usize;
 }
 
 pub const GMEM_FIXED: u32 = 0x0000;
@@ -462,6 +462,14 @@ pub const GWLP_HWNDPARENT: i32 = -8;
 pub const GWLP_USERDATA: i32 = -21;
 pub const GWLP_ID: i32 = -12;
 
+pub const GW_HWNDFIRST: u32 = 0;
+pub const GW_HWNDLAST: u32 = 1;
+pub const GW_HWNDNEXT: u32 = 2;
+pub const GW_HWNDPREV: u32 = 3;
+pub const GW_OWNER: u32 = 4;
+pub const GW_CHILD: u32 = 5;
+pub const GW_ENABLEDPOPUP: u32 = 6;
+
 pub const SM_CXSCREEN: i32 = 0;
 pub const SM_CYSCREEN: i32 = 1;
 pub const SM_CXVSCROLL: i32 = 2;
@@ -598,6 +606,20 @@ pub const PM_NOREMOVE: u32 = 0x0000;
 pub const PM_REMOVE: u32 = 0x0001;
 pub const PM_NOYIELD: u32 = 0x0002;
 
+pub const MK_LBUTTON: usize = 0x0001;
+pub const MK_RBUTTON: usize = 0x0002;
+pub const MK_SHIFT: usize = 0x0004;
+pub const MK_CONTROL: usize = 0x0008;
+pub const MK_MBUTTON: usize = 0x0010;
+pub const MK_XBUTTON1: usize = 0x0020;
+pub const MK_XBUTTON2: usize = 0x0040;
+
+pub const TME_HOVER: u32 = 0x00000001;
+pub const TME_LEAVE: u32 = 0x00000002;
+pub const TME_NONCLIENT: u32 = 0x00000010;
+pub const TME_QUERY: u32 = 0x40000000;
+pub const TME_CANCEL: u32 = 0x80000000;
+
 pub const WS_BORDER: u32 = 0x00800000;
 pub const WS_CAPTION: u32 = 0x00C00000;
 pub const WS_CHILD: u32 = 0x40000000;
@@ -655,6 +677,9 @@ pub const WS_EX_LAYOUTRTL: u32 = 0x00400000;
 pub const WS_EX_COMPOSITED: u32 = 0x02000000;
 pub const WS_EX_NOACTIVATE: u32 = 0x08000000;
 
+pub const LWA_COLORKEY: u32 = 0x00000001;
+pub const LWA_ALPHA: u32 = 0x00000002;
+
 pub const CS_BYTEALIGNCLIENT: u32 = 0x1000;
 pub const CS_BYTEALIGNWINDOW: u32 = 0x2000;
 pub const CS_CLASSDC: u32 = 0x0040;
@@ -703,9 +728,40 @@ pub const SW_SHOWDEFAULT: i32 = 10;
 pub const SW_FORCEMINIMIZE: i32 = 11;
 pub const SW_MAX: i32 = 11;
 
+pub const WPF_SETMINPOSITION: u32 = 0x0001;
+pub const WPF_RESTORETOMAXIMIZED: u32 = 0x0002;
+pub const WPF_ASYNCWINDOWPLACEMENT: u32 = 0x0004;
+
+pub const SIZE_RESTORED: usize = 0;
+pub const SIZE_MINIMIZED: usize = 1;
+pub const SIZE_MAXIMIZED: usize = 2;
+pub const SIZE_MAXSHOW: usize = 3;
+pub const SIZE_MAXHIDE: usize = 4;
+
+pub const WMSZ_LEFT: usize = 1;
+pub const WMSZ_RIGHT: usize = 2;
+pub const WMSZ_TOP: usize = 3;
+pub const WMSZ_TOPLEFT: usize = 4;
+pub const WMSZ_TOPRIGHT: usize = 5;
+pub const WMSZ_BOTTOM: usize = 6;
+pub const WMSZ_BOTTOMLEFT: usize = 7;
+pub const WMSZ_BOTTOMRIGHT: usize = 8;
+
+pub const FLASHW_STOP: u32 = 0;
+pub const FLASHW_CAPTION: u32 = 0x00000001;
+pub const FLASHW_TRAY: u32 = 0x00000002;
+pub const FLASHW_ALL: u32 = FLASHW_CAPTION | FLASHW_TRAY;
+pub const FLASHW_TIMER: u32 = 0x00000004;
+pub const FLASHW_TIMERNOFG: u32 = 0x0000000C;
+
 pub const GWL_EXSTYLE: i32 = -20;
 pub const GWL_STYLE: i32 = -16;
 
+pub const HWND_TOP: isize = 0;
+pub const HWND_BOTTOM: isize = 1;
+pub const HWND_TOPMOST: isize = -1;
+pub const HWND_NOTOPMOST: isize = -2;
+
 pub const SWP_NOSIZE: u32 = 0x0001;
 pub const SWP_NOMOVE: u32 = 0x0002;
 pub const SWP_NOZORDER: u32 = 0x0004;
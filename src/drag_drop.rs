@@ -0,0 +1,437 @@
+use crate::*;
+use std::{
+    path::PathBuf,
+    sync::atomic::{AtomicU32, Ordering::*},
+};
+
+pub const S_OK: HRESULT = 0;
+pub const S_FALSE: HRESULT = 1;
+pub const E_NOINTERFACE: HRESULT = 0x80004002u32 as i32;
+pub const E_UNEXPECTED: HRESULT = 0x8000FFFFu32 as i32;
+
+pub const DROPEFFECT_NONE: u32 = 0;
+pub const DROPEFFECT_COPY: u32 = 1;
+pub const DROPEFFECT_MOVE: u32 = 2;
+pub const DROPEFFECT_LINK: u32 = 4;
+
+pub const DVASPECT_CONTENT: u32 = 1;
+pub const TYMED_HGLOBAL: u32 = 1;
+
+pub const IID_IUNKNOWN: GUID = GUID::from_u128(0x00000000_0000_0000_c000_000000000046);
+pub const IID_IDROPTARGET: GUID = GUID::from_u128(0x00000122_0000_0000_c000_000000000046);
+
+#[link(name = "shell32")]
+extern "system" {
+    pub fn DragAcceptFiles(hwnd: isize, fAccept: BOOL);
+    pub fn DragFinish(hDrop: *mut c_void);
+}
+
+#[link(name = "ole32")]
+extern "system" {
+    pub fn OleInitialize(pvReserved: *mut c_void) -> HRESULT;
+    pub fn RegisterDragDrop(hwnd: isize, pDropTarget: *mut DropTarget) -> HRESULT;
+    pub fn RevokeDragDrop(hwnd: isize) -> HRESULT;
+    pub fn ReleaseStgMedium(medium: *mut STGMEDIUM);
+}
+
+///Header of a `CF_HDROP` global memory block.
+///The file list starts `pFiles` bytes from the start of this struct.
+///Each file is null terminated and the list ends with an extra null.
+#[repr(C)]
+#[derive(Debug, Default, Clone)]
+pub struct DROPFILES {
+    pub pFiles: DWORD,
+    pub pt: POINT,
+    pub fNC: BOOL,
+    pub fWide: BOOL,
+}
+
+#[repr(C)]
+#[derive(Debug)]
+pub struct FORMATETC {
+    pub cfFormat: u16,
+    pub ptd: *mut c_void,
+    pub dwAspect: DWORD,
+    pub lindex: LONG,
+    pub tymed: DWORD,
+}
+
+#[repr(C)]
+#[derive(Debug)]
+pub struct STGMEDIUM {
+    pub tymed: DWORD,
+    ///This is a union, only `TYMED_HGLOBAL` is used.
+    pub hGlobal: *mut c_void,
+    pub pUnkForRelease: *mut c_void,
+}
+
+///Parses the `CF_HDROP` layout, returns the drop point and the files.
+///The point is in client coordinates unless `fNC` is set.
+pub fn parse_hdrop(data: &[u8]) -> Option<(POINT, Vec<PathBuf>)> {
+    let read = |offset: usize| -> Option<u32> {
+        let bytes = data.get(offset..offset + 4)?;
+        Some(u32::from_le_bytes(bytes.try_into().ok()?))
+    };
+
+    let files = read(0)? as usize;
+    let pt = POINT {
+        x: read(4)? as i32,
+        y: read(8)? as i32,
+    };
+    let wide = read(16)? != 0;
+    let list = data.get(files..)?;
+
+    let paths = if wide {
+        let chars: Vec<u16> = list
+            .chunks_exact(2)
+            .map(|c| u16::from_le_bytes([c[0], c[1]]))
+            .collect();
+        chars
+            .split(|c| *c == 0)
+            .take_while(|path| !path.is_empty())
+            .map(|path| PathBuf::from(String::from_utf16_lossy(path)))
+            .collect()
+    } else {
+        list.split(|c| *c == 0)
+            .take_while(|path| !path.is_empty())
+            .map(|path| PathBuf::from(String::from_utf8_lossy(path).into_owned()))
+            .collect()
+    };
+
+    Some((pt, paths))
+}
+
+///Reads the files out of a `HDROP` from `WM_DROPFILES` or `IDataObject::GetData`.
+///
+///# Safety
+///
+///`hdrop` must be a valid global memory handle holding a `DROPFILES` structure.
+///It isn't freed, that's up to the caller.
+pub unsafe fn read_hdrop(hdrop: *mut c_void) -> Option<(POINT, Vec<PathBuf>)> {
+    let size = GlobalSize(hdrop);
+    let ptr = GlobalLock(hdrop) as *const u8;
+    if ptr.is_null() {
+        return None;
+    }
+    let result = parse_hdrop(core::slice::from_raw_parts(ptr, size));
+    GlobalUnlock(hdrop);
+    result
+}
+
+#[repr(C)]
+struct IDataObjectVtbl {
+    QueryInterface: usize,
+    AddRef: usize,
+    Release: usize,
+    GetData: unsafe extern "system" fn(
+        this: *mut IDataObject,
+        format: *const FORMATETC,
+        medium: *mut STGMEDIUM,
+    ) -> HRESULT,
+}
+
+#[repr(C)]
+pub struct IDataObject {
+    vtable: *const IDataObjectVtbl,
+}
+
+impl IDataObject {
+    ///The files in `CF_HDROP` format, `None` if the data isn't files.
+    ///
+    ///# Safety
+    ///
+    ///`this` must be null or a live `IDataObject`, like the one passed to `IDropTarget::DragEnter`.
+    pub unsafe fn files(this: *mut IDataObject) -> Option<(POINT, Vec<PathBuf>)> {
+        if this.is_null() {
+            return None;
+        }
+
+        let format = FORMATETC {
+            cfFormat: CF_HDROP as u16,
+            ptd: null_mut(),
+            dwAspect: DVASPECT_CONTENT,
+            lindex: -1,
+            tymed: TYMED_HGLOBAL,
+        };
+        let mut medium: STGMEDIUM = core::mem::zeroed();
+        if ((*(*this).vtable).GetData)(this, &format, &mut medium) < 0 {
+            return None;
+        }
+
+        let files = read_hdrop(medium.hGlobal);
+        ReleaseStgMedium(&mut medium);
+        files
+    }
+}
+
+#[repr(C)]
+struct IDropTargetVtbl {
+    QueryInterface: unsafe extern "system" fn(
+        this: *mut DropTarget,
+        riid: *const GUID,
+        object: *mut *mut c_void,
+    ) -> HRESULT,
+    AddRef: unsafe extern "system" fn(this: *mut DropTarget) -> u32,
+    Release: unsafe extern "system" fn(this: *mut DropTarget) -> u32,
+    DragEnter: unsafe extern "system" fn(
+        this: *mut DropTarget,
+        data: *mut IDataObject,
+        key_state: DWORD,
+        pt: POINT,
+        effect: *mut DWORD,
+    ) -> HRESULT,
+    DragOver: unsafe extern "system" fn(
+        this: *mut DropTarget,
+        key_state: DWORD,
+        pt: POINT,
+        effect: *mut DWORD,
+    ) -> HRESULT,
+    DragLeave: unsafe extern "system" fn(this: *mut DropTarget) -> HRESULT,
+    Drop: unsafe extern "system" fn(
+        this: *mut DropTarget,
+        data: *mut IDataObject,
+        key_state: DWORD,
+        pt: POINT,
+        effect: *mut DWORD,
+    ) -> HRESULT,
+}
+
+static DROP_TARGET_VTBL: IDropTargetVtbl = IDropTargetVtbl {
+    QueryInterface: DropTarget::query_interface,
+    AddRef: DropTarget::add_ref,
+    Release: DropTarget::release,
+    DragEnter: DropTarget::drag_enter,
+    DragOver: DropTarget::drag_over,
+    DragLeave: DropTarget::drag_leave,
+    Drop: DropTarget::drop,
+};
+
+///A minimal `IDropTarget` that forwards OLE drag and drop into the window's events.
+#[repr(C)]
+#[derive(Debug)]
+pub struct DropTarget {
+    vtable: *const IDropTargetVtbl,
+    refs: AtomicU32,
+    hwnd: isize,
+    ///Only files can be dropped, anything else is rejected.
+    has_files: bool,
+}
+
+impl DropTarget {
+    pub fn new(hwnd: isize) -> *mut DropTarget {
+        Box::into_raw(Box::new(DropTarget {
+            vtable: &DROP_TARGET_VTBL,
+            refs: AtomicU32::new(1),
+            hwnd,
+            has_files: false,
+        }))
+    }
+    unsafe fn window(&mut self) -> Option<&mut Window> {
+        let ptr = GetWindowLongPtrW(self.hwnd, GWLP_USERDATA) as *mut Window;
+        ptr.as_mut()
+    }
+    unsafe fn set_position(&mut self, pt: POINT) {
+        let mut pt = pt;
+        ScreenToClient(self.hwnd, &mut pt);
+        if let Some(window) = self.window() {
+            window.drop_position = (pt.x, pt.y);
+        }
+    }
+    unsafe extern "system" fn query_interface(
+        this: *mut DropTarget,
+        riid: *const GUID,
+        object: *mut *mut c_void,
+    ) -> HRESULT {
+        if *riid == IID_IUNKNOWN || *riid == IID_IDROPTARGET {
+            Self::add_ref(this);
+            *object = this as *mut c_void;
+            S_OK
+        } else {
+            *object = null_mut();
+            E_NOINTERFACE
+        }
+    }
+    unsafe extern "system" fn add_ref(this: *mut DropTarget) -> u32 {
+        (*this).refs.fetch_add(1, Relaxed) + 1
+    }
+    pub(crate) unsafe extern "system" fn release(this: *mut DropTarget) -> u32 {
+        let refs = (*this).refs.fetch_sub(1, Release) - 1;
+        if refs == 0 {
+            drop(Box::from_raw(this));
+        }
+        refs
+    }
+    unsafe extern "system" fn drag_enter(
+        this: *mut DropTarget,
+        data: *mut IDataObject,
+        _key_state: DWORD,
+        pt: POINT,
+        effect: *mut DWORD,
+    ) -> HRESULT {
+        let target = &mut *this;
+        let files = IDataObject::files(data);
+        target.has_files = files.is_some();
+        target.set_position(pt);
+
+        if let (Some((_, files)), Some(window)) = (files, target.window()) {
+            let events = window.events.get_mut();
+            events.extend(files.into_iter().map(Event::FileHovered));
+        }
+
+        *effect = if target.has_files {
+            DROPEFFECT_COPY
+        } else {
+            DROPEFFECT_NONE
+        };
+        S_OK
+    }
+    unsafe extern "system" fn drag_over(
+        this: *mut DropTarget,
+        _key_state: DWORD,
+        pt: POINT,
+        effect: *mut DWORD,
+    ) -> HRESULT {
+        let target = &mut *this;
+        target.set_position(pt);
+        *effect = if target.has_files {
+            DROPEFFECT_COPY
+        } else {
+            DROPEFFECT_NONE
+        };
+        S_OK
+    }
+    unsafe extern "system" fn drag_leave(this: *mut DropTarget) -> HRESULT {
+        let target = &mut *this;
+        if target.has_files {
+            if let Some(window) = target.window() {
+                window.events.get_mut().push_back(Event::HoverCancelled);
+            }
+        }
+        target.has_files = false;
+        S_OK
+    }
+    unsafe extern "system" fn drop(
+        this: *mut DropTarget,
+        data: *mut IDataObject,
+        _key_state: DWORD,
+        pt: POINT,
+        effect: *mut DWORD,
+    ) -> HRESULT {
+        let target = &mut *this;
+        target.has_files = false;
+        target.set_position(pt);
+
+        let Some((_, files)) = IDataObject::files(data) else {
+            *effect = DROPEFFECT_NONE;
+            return S_OK;
+        };
+
+        if let Some(window) = target.window() {
+            window.events.get_mut().push_back(Event::FileDropped(files));
+        }
+
+        *effect = DROPEFFECT_COPY;
+        S_OK
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    ///A `DROPFILES` header followed by the file list.
+    fn dropfiles(x: i32, y: i32, wide: bool, list: &[u8]) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend(20u32.to_le_bytes());
+        data.extend(x.to_le_bytes());
+        data.extend(y.to_le_bytes());
+        data.extend(0u32.to_le_bytes());
+        data.extend((wide as u32).to_le_bytes());
+        data.extend(list);
+        data
+    }
+
+    fn wide_list(paths: &[&str]) -> Vec<u8> {
+        let mut list = Vec::new();
+        for path in paths {
+            list.extend(path.encode_utf16().chain([0]).flat_map(u16::to_le_bytes));
+        }
+        list.extend([0, 0]);
+        list
+    }
+
+    #[test]
+    fn wide() {
+        let data = dropfiles(5, -7, true, &wide_list(&["C:\\a.txt", "C:\\ü 🙂.png"]));
+        let (pt, files) = parse_hdrop(&data).unwrap();
+        assert_eq!((pt.x, pt.y), (5, -7));
+        assert_eq!(
+            files,
+            vec![PathBuf::from("C:\\a.txt"), PathBuf::from("C:\\ü 🙂.png")]
+        );
+    }
+
+    #[test]
+    fn ansi() {
+        let data = dropfiles(1, 2, false, b"C:\\a.txt\0D:\\b\0\0");
+        let (pt, files) = parse_hdrop(&data).unwrap();
+        assert_eq!((pt.x, pt.y), (1, 2));
+        assert_eq!(
+            files,
+            vec![PathBuf::from("C:\\a.txt"), PathBuf::from("D:\\b")]
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let (_, files) = parse_hdrop(&dropfiles(0, 0, true, &[0, 0])).unwrap();
+        assert!(files.is_empty());
+
+        let (_, files) = parse_hdrop(&dropfiles(0, 0, false, b"\0")).unwrap();
+        assert!(files.is_empty());
+
+        //Nothing after the header at all.
+        let (_, files) = parse_hdrop(&dropfiles(0, 0, true, &[])).unwrap();
+        assert!(files.is_empty());
+    }
+
+    #[test]
+    fn truncated() {
+        let data = dropfiles(0, 0, true, &wide_list(&["C:\\a.txt"]));
+        for len in 0..20 {
+            assert!(parse_hdrop(&data[..len]).is_none(), "{len}");
+        }
+
+        //The list is cut off before the terminators, keep what's there.
+        let (_, files) = parse_hdrop(&data[..data.len() - 5]).unwrap();
+        assert_eq!(files, vec![PathBuf::from("C:\\a.tx")]);
+
+        //Odd number of bytes in a wide list.
+        let (_, files) = parse_hdrop(&data[..data.len() - 3]).unwrap();
+        assert_eq!(files, vec![PathBuf::from("C:\\a.txt")]);
+    }
+
+    #[test]
+    fn garbage() {
+        //The file list offset points past the end.
+        let mut data = dropfiles(0, 0, true, &wide_list(&["C:\\a.txt"]));
+        data[..4].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(parse_hdrop(&data).is_none());
+
+        //Unpaired surrogates and invalid UTF-8 are replaced, not rejected.
+        let data = dropfiles(0, 0, true, &[0x00, 0xD8, b'a', 0, 0, 0, 0, 0]);
+        let (_, files) = parse_hdrop(&data).unwrap();
+        assert_eq!(files, vec![PathBuf::from("\u{FFFD}a")]);
+
+        let data = dropfiles(0, 0, false, &[0xFF, 0xFE, 0, 0]);
+        let (_, files) = parse_hdrop(&data).unwrap();
+        assert_eq!(files, vec![PathBuf::from("\u{FFFD}\u{FFFD}")]);
+
+        for seed in 0..64u32 {
+            let bytes: Vec<u8> = (0..seed * 3)
+                .map(|i| (i.wrapping_mul(2654435761).wrapping_add(seed) >> 7) as u8)
+                .collect();
+            let _ = parse_hdrop(&bytes);
+        }
+    }
+}
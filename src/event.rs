@@ -1,4 +1,5 @@
 use crate::*;
+use std::path::PathBuf;
 
 #[derive(Debug, PartialEq)]
 pub enum Modifier {
@@ -13,9 +14,73 @@ pub enum Modifier {
 
 #[derive(Debug, PartialEq)]
 pub enum Event {
+    ///The window was destroyed.
     Quit,
-    ///Mouse movement inside the window. (0, 0) is top left of window.
-    MouseMove(i32, i32),
+    ///The close button, alt-f4 or the taskbar was used to close the window.
+    ///Nothing happens unless `Window::close` is called, so this can be ignored.
+    CloseRequested,
+    ///Sent from another thread with `EventProxy::send`.
+    User(UserEvent),
+    ///Sent from another thread with `EventProxy::wake`.
+    Wake,
+    ///Monitors were plugged in, unplugged, rearranged or changed resolution or scale.
+    ///Only returned from `EventLoop`, see `MonitorWatcher` when using a single `Window`.
+    MonitorsChanged(Vec<Monitor>),
+    ///Part of the window was uncovered or resized, or `Window::request_redraw` was called.
+    ///The last buffer that was drawn has already been shown.
+    RedrawRequested,
+    ///Mouse movement inside the window in client coordinates.
+    MouseMove(PhysicalPosition),
+    ///The mouse entered the client area.
+    MouseEnter,
+    ///The mouse left the client area.
+    MouseLeave,
+    ///Touch input in client coordinates. Each finger has it's own `id`.
+    Touch {
+        id: u32,
+        phase: TouchPhase,
+        x: i32,
+        y: i32,
+    },
+    ///Pen input in client coordinates.
+    ///Pressure ranges from 0.0 to 1.0 and tilt ranges from -90 to 90 degrees.
+    ///`buttons` contains the `PEN_FLAG_*` bits.
+    Pen {
+        x: i32,
+        y: i32,
+        pressure: f32,
+        tilt: (i32, i32),
+        buttons: u32,
+        eraser: bool,
+    },
+    ///Content should move by this many pixels.
+    ///Generated from touch drags and touchpad scrolling.
+    Pan {
+        dx: f32,
+        dy: f32,
+    },
+    ///Relative change in zoom since the last pinch, 1.0 is no change.
+    ///Generated from two finger touch and touchpad pinching.
+    Pinch {
+        scale: f32,
+    },
+    ///Relative rotation since the last rotate, clockwise is positive.
+    Rotate {
+        radians: f32,
+    },
+    ///A file is being dragged over the window. Sent once per file.
+    ///See `Window::drop_position` for where.
+    FileHovered(PathBuf),
+    ///Files were dropped onto the window at `Window::drop_position`.
+    FileDropped(Vec<PathBuf>),
+    ///The files being dragged left the window or the drag was cancelled.
+    HoverCancelled,
+    ///Controller input from a `GamepadSource`.
+    Gamepad {
+        id: u32,
+        button: Option<(GamepadButton, bool)>,
+        axis: Option<(GamepadAxis, f32)>,
+    },
     Input(Key, Modifiers),
 }
 
@@ -85,11 +150,14 @@ pub fn translate_message(msg: MSG, message_result: i32) -> Option<Event> {
         return None;
     } else if message_result == -1 {
         let last_error = unsafe { GetLastError() };
-        panic!("Error with `GetMessageA`, error code: {}", last_error);
+        panic!("Error with `GetMessageW`, error code: {}", last_error);
     }
 
     let key = match msg.message {
         WM_MOUSEWHEEL => {
+            //Let the window turn this into a pan or pinch as well.
+            unsafe { wnd_proc(msg.hwnd, msg.message, msg.w_param, msg.l_param) };
+
             const WHEEL_DELTA: i16 = 120;
             let value = (msg.w_param >> 16) as i16;
             let delta = value as f32 / WHEEL_DELTA as f32;
@@ -0,0 +1,199 @@
+use crate::*;
+use std::{
+    pin::Pin,
+    time::{Duration, Instant},
+};
+
+///Identifies a window owned by an `EventLoop`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct WindowId(pub isize);
+
+impl WindowId {
+    ///Events that aren't for any one window, such as `Event::MonitorsChanged`.
+    pub const NONE: WindowId = WindowId(0);
+}
+
+///Owns every window on the thread and pumps the thread's message queue once for all of them.
+///
+///`Window::event` only reads messages for it's own `hwnd`, so other windows and
+///thread messages are never dispatched. Use this when there is more than one window.
+#[derive(Debug)]
+pub struct EventLoop {
+    pub windows: Vec<Pin<Box<Window>>>,
+    ///Used by `EventLoop::next_event`.
+    pub control_flow: ControlFlow,
+    pub monitors: MonitorWatcher,
+    ///Windows that have already reported `Event::Quit`.
+    quit: Vec<WindowId>,
+}
+
+impl Default for EventLoop {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EventLoop {
+    pub fn new() -> Self {
+        Self {
+            windows: Vec::new(),
+            control_flow: ControlFlow::default(),
+            monitors: MonitorWatcher::new(),
+            quit: Vec::new(),
+        }
+    }
+    pub fn create_window<U: Unit>(
+        &mut self,
+        title: &str,
+        position: Option<PhysicalPosition>,
+        size: Size<U>,
+        style: WindowStyle,
+    ) -> WindowId {
+        self.add(create_window(title, position, size, style))
+    }
+    pub fn add(&mut self, window: Pin<Box<Window>>) -> WindowId {
+        let id = window.id();
+        self.windows.push(window);
+        id
+    }
+    pub fn remove(&mut self, id: WindowId) -> Option<Pin<Box<Window>>> {
+        self.quit.retain(|quit| *quit != id);
+        let index = self.windows.iter().position(|w| w.id() == id)?;
+        Some(self.windows.remove(index))
+    }
+    pub fn window(&self, id: WindowId) -> Option<&Window> {
+        self.windows.iter().find(|w| w.id() == id).map(|w| &**w)
+    }
+    pub fn window_mut(&mut self, id: WindowId) -> Option<&mut Window> {
+        self.windows
+            .iter_mut()
+            .find(|w| w.id() == id)
+            .map(|w| &mut **w)
+    }
+    ///Events that were queued by `wnd_proc` or an `EventProxy`, or a window that was destroyed.
+    fn pending(&mut self) -> Option<(WindowId, Event)> {
+        if let Some(monitors) = self.monitors.changed() {
+            return Some((WindowId::NONE, Event::MonitorsChanged(monitors)));
+        }
+        for window in &self.windows {
+            let id = window.id();
+            if window.quit && !self.quit.contains(&id) {
+                self.quit.push(id);
+                return Some((id, Event::Quit));
+            }
+            if let Some(event) = window.pending() {
+                return Some((id, event));
+            }
+        }
+        None
+    }
+    fn poll_gamepads(&self) -> Option<(WindowId, Event)> {
+        self.windows
+            .iter()
+            .find_map(|w| w.poll_gamepads().map(|event| (w.id(), event)))
+    }
+    ///Translates messages for our windows and dispatches everything else.
+    fn dispatch(&mut self, msg: MSG, result: i32) -> Option<(WindowId, Event)> {
+        //Each window reports it's own `Event::Quit` when it's destroyed.
+        if msg.message == WM_QUIT {
+            return None;
+        }
+
+        let id = WindowId(msg.hwnd);
+        if msg.hwnd != 0 && self.window(id).is_some() {
+            translate_message(msg, result).map(|event| (id, event))
+        } else {
+            unsafe {
+                TranslateMessage(&msg);
+                DispatchMessageW(&msg);
+            }
+            None
+        }
+    }
+    pub fn event(&mut self) -> Option<(WindowId, Event)> {
+        if let Some(event) = self.pending() {
+            return Some(event);
+        }
+
+        let event = unsafe {
+            let mut msg = MSG::new();
+            let result = PeekMessageW(&mut msg, 0, 0, 0, PM_REMOVE);
+            if result == 0 {
+                None
+            } else {
+                self.dispatch(msg, result)
+            }
+        };
+
+        event
+            .or_else(|| self.pending())
+            .or_else(|| self.poll_gamepads())
+    }
+    ///Waits for an event, returns `None` if nothing arrived in time.
+    pub fn event_timeout(&mut self, timeout: Duration) -> Option<(WindowId, Event)> {
+        self.wait_until(Instant::now() + timeout)
+    }
+    ///Waits for an event, returns `None` once the deadline has passed.
+    pub fn wait_until(&mut self, deadline: Instant) -> Option<(WindowId, Event)> {
+        loop {
+            if let Some(event) = self.event() {
+                return Some(event);
+            }
+
+            let now = Instant::now();
+            if now >= deadline || !wait_for_message(deadline - now) {
+                return None;
+            }
+        }
+    }
+    ///Gets the next event based on `control_flow`.
+    ///
+    ///```no_run
+    ///# use window::*;
+    ///# use std::time::{Duration, Instant};
+    ///let mut event_loop = EventLoop::new();
+    ///event_loop.create_window("Window", None, LogicalSize::new(600, 400), WindowStyle::DEFAULT);
+    ///
+    ///let frame = Duration::from_millis(16);
+    ///event_loop.control_flow = ControlFlow::WaitUntil(Instant::now() + frame);
+    ///loop {
+    ///    match event_loop.next_event() {
+    ///        Some((_, Event::CloseRequested)) => break,
+    ///        Some(_) => {}
+    ///        //Deadline reached, time for the next frame.
+    ///        None => event_loop.control_flow = ControlFlow::WaitUntil(Instant::now() + frame),
+    ///    }
+    ///}
+    ///```
+    pub fn next_event(&mut self) -> Option<(WindowId, Event)> {
+        match self.control_flow {
+            ControlFlow::Poll => self.event(),
+            ControlFlow::Wait => self.event_blocking(),
+            ControlFlow::WaitUntil(deadline) => self.wait_until(deadline),
+        }
+    }
+    ///Waits until one of the windows has an event.
+    pub fn event_blocking(&mut self) -> Option<(WindowId, Event)> {
+        loop {
+            if let Some(event) = self.pending() {
+                return Some(event);
+            }
+
+            let event = unsafe {
+                let mut msg = MSG::new();
+                let result = GetMessageW(&mut msg, 0, 0, 0);
+                if result == -1 {
+                    panic!("Error with `GetMessageW`, error code: {}", GetLastError());
+                }
+                self.dispatch(msg, result)
+            };
+
+            if let Some(event) = event
+                .or_else(|| self.pending())
+                .or_else(|| self.poll_gamepads())
+            {
+                return Some(event);
+            }
+        }
+    }
+}
@@ -0,0 +1,167 @@
+use crate::*;
+use std::collections::VecDeque;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GamepadButton {
+    A,
+    B,
+    X,
+    Y,
+    Start,
+    Back,
+    LeftShoulder,
+    RightShoulder,
+    LeftThumb,
+    RightThumb,
+    DPadUp,
+    DPadDown,
+    DPadLeft,
+    DPadRight,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GamepadAxis {
+    LeftX,
+    LeftY,
+    RightX,
+    RightY,
+    LeftTrigger,
+    RightTrigger,
+}
+
+///A single change in controller state.
+///`button` is the button and whether it's pressed.
+///`axis` is the axis and it's value, sticks are -1.0 to 1.0 and triggers are 0.0 to 1.0.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct GamepadEvent {
+    pub id: u32,
+    pub button: Option<(GamepadButton, bool)>,
+    pub axis: Option<(GamepadAxis, f32)>,
+}
+
+impl From<GamepadEvent> for Event {
+    fn from(event: GamepadEvent) -> Self {
+        Event::Gamepad {
+            id: event.id,
+            button: event.button,
+            axis: event.axis,
+        }
+    }
+}
+
+///Integration point for controller input, such as the XInput crate.
+///Sources are polled along with the window's messages, see `Window::add_gamepad`.
+pub trait GamepadSource: core::fmt::Debug {
+    ///Returns the next change in controller state. This must not block.
+    fn poll(&mut self) -> Option<GamepadEvent>;
+}
+
+///The next event from the first source that has one.
+pub fn poll_gamepads(sources: &mut [Box<dyn GamepadSource>]) -> Option<Event> {
+    sources.iter_mut().find_map(|g| g.poll()).map(Event::from)
+}
+
+///A gamepad that replays whatever is pushed into it.
+///Useful for testing controller navigation without a controller.
+#[derive(Debug, Default, Clone)]
+pub struct MockGamepad {
+    pub events: VecDeque<GamepadEvent>,
+}
+
+impl MockGamepad {
+    pub const fn new() -> Self {
+        Self {
+            events: VecDeque::new(),
+        }
+    }
+    pub fn press(&mut self, id: u32, button: GamepadButton) {
+        self.events.push_back(GamepadEvent {
+            id,
+            button: Some((button, true)),
+            axis: None,
+        });
+    }
+    pub fn release(&mut self, id: u32, button: GamepadButton) {
+        self.events.push_back(GamepadEvent {
+            id,
+            button: Some((button, false)),
+            axis: None,
+        });
+    }
+    pub fn axis(&mut self, id: u32, axis: GamepadAxis, value: f32) {
+        self.events.push_back(GamepadEvent {
+            id,
+            button: None,
+            axis: Some((axis, value)),
+        });
+    }
+}
+
+impl GamepadSource for MockGamepad {
+    fn poll(&mut self) -> Option<GamepadEvent> {
+        self.events.pop_front()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mock() {
+        let mut pad = MockGamepad::new();
+        pad.press(0, GamepadButton::A);
+        pad.axis(0, GamepadAxis::LeftX, -0.5);
+        pad.release(0, GamepadButton::A);
+
+        let mut sources: Vec<Box<dyn GamepadSource>> = vec![Box::new(pad)];
+        assert_eq!(
+            poll_gamepads(&mut sources),
+            Some(Event::Gamepad {
+                id: 0,
+                button: Some((GamepadButton::A, true)),
+                axis: None,
+            })
+        );
+        assert_eq!(
+            poll_gamepads(&mut sources),
+            Some(Event::Gamepad {
+                id: 0,
+                button: None,
+                axis: Some((GamepadAxis::LeftX, -0.5)),
+            })
+        );
+        assert_eq!(
+            poll_gamepads(&mut sources),
+            Some(Event::Gamepad {
+                id: 0,
+                button: Some((GamepadButton::A, false)),
+                axis: None,
+            })
+        );
+        assert_eq!(poll_gamepads(&mut sources), None);
+    }
+
+    #[test]
+    fn multiple_sources() {
+        let mut first = MockGamepad::new();
+        first.press(0, GamepadButton::Start);
+        let mut second = MockGamepad::new();
+        second.press(1, GamepadButton::B);
+        second.axis(1, GamepadAxis::RightTrigger, 1.0);
+
+        let mut sources: Vec<Box<dyn GamepadSource>> = vec![Box::new(first), Box::new(second)];
+        let ids: Vec<u32> = std::iter::from_fn(|| poll_gamepads(&mut sources))
+            .map(|event| match event {
+                Event::Gamepad { id, .. } => id,
+                event => panic!("{event:?}"),
+            })
+            .collect();
+        assert_eq!(ids, [0, 1, 1]);
+    }
+
+    #[test]
+    fn no_sources() {
+        assert_eq!(poll_gamepads(&mut []), None);
+    }
+}
@@ -60,6 +60,72 @@ pub fn capture_virtual_screen() -> (Vec<u32>, i32, i32, i32, i32) {
     }
 }
 
+///Converts straight alpha `0xAARRGGBB` into the premultiplied alpha `UpdateLayeredWindow` expects.
+pub fn premultiply(src: &[u32], dst: &mut [u32]) {
+    for (dst, src) in dst.iter_mut().zip(src) {
+        let a = src >> 24;
+        let mul = |c: u32| (c * a + 127) / 255;
+        let r = mul((src >> 16) & 0xFF);
+        let g = mul((src >> 8) & 0xFF);
+        let b = mul(src & 0xFF);
+        *dst = a << 24 | r << 16 | g << 8 | b;
+    }
+}
+
+///A 32-bit DIB section selected into a memory DC.
+///Used as the source for `UpdateLayeredWindow`.
+#[derive(Debug)]
+pub struct LayeredSurface {
+    pub dc: *mut c_void,
+    pub bitmap: *mut c_void,
+    pub bits: *mut u32,
+    pub width: i32,
+    pub height: i32,
+}
+
+impl LayeredSurface {
+    pub fn new(width: i32, height: i32) -> Self {
+        unsafe {
+            let dc = CreateCompatibleDC(null_mut());
+            assert!(!dc.is_null());
+
+            let mut bits = null_mut();
+            let bitmap = CreateDIBSection(
+                dc,
+                &BITMAPINFO::new(width, height),
+                DIB_RGB_COLORS,
+                &mut bits,
+                null_mut(),
+                0,
+            );
+            assert!(!bitmap.is_null() && !bits.is_null());
+            SelectObject(dc, bitmap);
+
+            Self {
+                dc,
+                bitmap,
+                bits: bits as *mut u32,
+                width,
+                height,
+            }
+        }
+    }
+    pub fn pixels(&mut self) -> &mut [u32] {
+        unsafe {
+            core::slice::from_raw_parts_mut(self.bits, self.width as usize * self.height as usize)
+        }
+    }
+}
+
+impl Drop for LayeredSurface {
+    fn drop(&mut self) {
+        unsafe {
+            DeleteDC(self.dc);
+            DeleteObject(self.bitmap);
+        }
+    }
+}
+
 pub const SRCCOPY: u32 = 0x00CC0020;
 pub const DEFAULT_CHARSET: DWORD = 1;
 pub const OUT_OUTLINE_PRECIS: DWORD = 8;
@@ -85,6 +151,13 @@ extern "system" {
     ) -> i32;
 }
 
+#[link(name = "user32")]
+extern "system" {
+    pub fn BeginPaint(hwnd: isize, lpPaint: *mut PAINTSTRUCT) -> *mut c_void;
+    pub fn EndPaint(hwnd: isize, lpPaint: *const PAINTSTRUCT) -> BOOL;
+    pub fn InvalidateRect(hwnd: isize, lpRect: *const RECT, bErase: BOOL) -> BOOL;
+}
+
 #[link(name = "Gdi32")]
 extern "system" {
     pub fn StretchDIBits(
@@ -226,6 +299,41 @@ pub const AC_SRC_ALPHA: u8 = 0x01;
 pub const DIB_RGB_COLORS: DWORD = 0;
 pub const DIB_PAL_COLORS: DWORD = 1;
 
+pub const ULW_COLORKEY: u32 = 0x00000001;
+pub const ULW_ALPHA: u32 = 0x00000002;
+pub const ULW_OPAQUE: u32 = 0x00000004;
+
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SIZE {
+    pub cx: i32,
+    pub cy: i32,
+}
+
+#[repr(C)]
+#[derive(Debug)]
+pub struct PAINTSTRUCT {
+    pub hdc: *mut c_void,
+    pub fErase: BOOL,
+    pub rcPaint: RECT,
+    pub fRestore: BOOL,
+    pub fIncUpdate: BOOL,
+    pub rgbReserved: [u8; 32],
+}
+
+impl Default for PAINTSTRUCT {
+    fn default() -> Self {
+        Self {
+            hdc: null_mut(),
+            fErase: 0,
+            rcPaint: RECT::default(),
+            fRestore: 0,
+            fIncUpdate: 0,
+            rgbReserved: [0; 32],
+        }
+    }
+}
+
 #[repr(C)]
 #[derive(Debug, Default)]
 pub struct BLENDFUNCTION {
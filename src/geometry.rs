@@ -0,0 +1,457 @@
+//! Signed geometry for screen and window coordinates.
+//!
+//! Monitors to the left of or above the primary monitor have negative coordinates,
+//! so everything here is `i32` like Win32. The unit says whether the values are
+//! raw pixels (`Physical`) or pixels at 96 DPI (`Logical`).
+use crate::*;
+use core::marker::PhantomData;
+
+///Raw pixels, what Win32 uses.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Physical;
+
+///Pixels at 100% display scale (96 DPI).
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Logical;
+
+///Converts between units with the display scale, see `Window::display_scale`.
+pub trait Unit: Copy + Default {
+    ///How many physical pixels are in one of this unit.
+    fn pixels(display_scale: f32) -> f64;
+}
+
+impl Unit for Physical {
+    fn pixels(_: f32) -> f64 {
+        1.0
+    }
+}
+
+impl Unit for Logical {
+    fn pixels(display_scale: f32) -> f64 {
+        display_scale as f64
+    }
+}
+
+pub type PhysicalPosition = Point<Physical>;
+pub type LogicalPosition = Point<Logical>;
+pub type PhysicalSize = Size<Physical>;
+pub type LogicalSize = Size<Logical>;
+
+///Rounds to the nearest pixel, `from` and `to` are physical pixels per unit.
+#[inline]
+fn convert(value: i32, from: f64, to: f64) -> i32 {
+    (value as f64 * from / to).round() as i32
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Point<U = Physical> {
+    pub x: i32,
+    pub y: i32,
+    unit: PhantomData<U>,
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Size<U = Physical> {
+    pub width: i32,
+    pub height: i32,
+    unit: PhantomData<U>,
+}
+
+///A signed rectangle. `x` and `y` are the top left, the right and bottom edges are exclusive.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct IRect<U = Physical> {
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+    unit: PhantomData<U>,
+}
+
+///Saturates instead of wrapping.
+#[inline]
+const fn saturate(value: i64) -> i32 {
+    if value > i32::MAX as i64 {
+        i32::MAX
+    } else if value < i32::MIN as i64 {
+        i32::MIN
+    } else {
+        value as i32
+    }
+}
+
+impl<U> Point<U> {
+    pub const fn new(x: i32, y: i32) -> Self {
+        Self {
+            x,
+            y,
+            unit: PhantomData,
+        }
+    }
+    pub const fn offset(self, dx: i32, dy: i32) -> Self {
+        Self::new(self.x.saturating_add(dx), self.y.saturating_add(dy))
+    }
+    ///Moves the point inside of `rect`. Empty rects put the point on the top left.
+    pub const fn clamp(self, rect: IRect<U>) -> Self {
+        let right = saturate(rect.right() - 1);
+        let bottom = saturate(rect.bottom() - 1);
+        let x = if self.x < rect.x || rect.width <= 0 {
+            rect.x
+        } else if self.x > right {
+            right
+        } else {
+            self.x
+        };
+        let y = if self.y < rect.y || rect.height <= 0 {
+            rect.y
+        } else if self.y > bottom {
+            bottom
+        } else {
+            self.y
+        };
+        Self::new(x, y)
+    }
+}
+
+impl<U: Unit> Point<U> {
+    pub fn to_physical(self, display_scale: f32) -> PhysicalPosition {
+        let from = U::pixels(display_scale);
+        Point::new(convert(self.x, from, 1.0), convert(self.y, from, 1.0))
+    }
+    pub fn to_logical(self, display_scale: f32) -> LogicalPosition {
+        let (from, to) = (U::pixels(display_scale), display_scale as f64);
+        Point::new(convert(self.x, from, to), convert(self.y, from, to))
+    }
+}
+
+impl Point<Physical> {
+    ///Signed client coordinates from a mouse message.
+    ///Positions can be negative while the mouse is captured.
+    pub const fn from_lparam(lparam: isize) -> Self {
+        Self::new(lparam as i16 as i32, (lparam >> 16) as i16 as i32)
+    }
+}
+
+impl<U> Size<U> {
+    pub const fn new(width: i32, height: i32) -> Self {
+        Self {
+            width,
+            height,
+            unit: PhantomData,
+        }
+    }
+    pub const fn is_empty(&self) -> bool {
+        self.width <= 0 || self.height <= 0
+    }
+}
+
+impl<U: Unit> Size<U> {
+    ///```
+    ///# use window::*;
+    ///assert_eq!(LogicalSize::new(800, 600).to_physical(1.5), PhysicalSize::new(1200, 900));
+    ///```
+    pub fn to_physical(self, display_scale: f32) -> PhysicalSize {
+        let from = U::pixels(display_scale);
+        Size::new(
+            convert(self.width, from, 1.0),
+            convert(self.height, from, 1.0),
+        )
+    }
+    pub fn to_logical(self, display_scale: f32) -> LogicalSize {
+        let (from, to) = (U::pixels(display_scale), display_scale as f64);
+        Size::new(
+            convert(self.width, from, to),
+            convert(self.height, from, to),
+        )
+    }
+}
+
+impl<U: Unit> IRect<U> {
+    pub fn to_physical(self, display_scale: f32) -> IRect<Physical> {
+        IRect::from_position_size(
+            self.position().to_physical(display_scale),
+            self.size().to_physical(display_scale),
+        )
+    }
+    pub fn to_logical(self, display_scale: f32) -> IRect<Logical> {
+        IRect::from_position_size(
+            self.position().to_logical(display_scale),
+            self.size().to_logical(display_scale),
+        )
+    }
+}
+
+impl<U> IRect<U> {
+    pub const fn new(x: i32, y: i32, width: i32, height: i32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+            unit: PhantomData,
+        }
+    }
+    pub const fn from_position_size(position: Point<U>, size: Size<U>) -> Self {
+        Self::new(position.x, position.y, size.width, size.height)
+    }
+    ///Computed with `i64` so it can't overflow.
+    pub const fn right(&self) -> i64 {
+        self.x as i64 + self.width as i64
+    }
+    ///Computed with `i64` so it can't overflow.
+    pub const fn bottom(&self) -> i64 {
+        self.y as i64 + self.height as i64
+    }
+    pub const fn position(&self) -> Point<U> {
+        Point::new(self.x, self.y)
+    }
+    pub const fn size(&self) -> Size<U> {
+        Size::new(self.width, self.height)
+    }
+    pub const fn is_empty(&self) -> bool {
+        self.width <= 0 || self.height <= 0
+    }
+    pub const fn contains(&self, point: Point<U>) -> bool {
+        point.x >= self.x
+            && point.y >= self.y
+            && (point.x as i64) < self.right()
+            && (point.y as i64) < self.bottom()
+    }
+    pub const fn offset(self, dx: i32, dy: i32) -> Self {
+        Self::new(
+            self.x.saturating_add(dx),
+            self.y.saturating_add(dy),
+            self.width,
+            self.height,
+        )
+    }
+    ///The overlapping area, `None` if they don't overlap.
+    pub const fn intersection(&self, other: IRect<U>) -> Option<Self> {
+        let x = if self.x > other.x { self.x } else { other.x };
+        let y = if self.y > other.y { self.y } else { other.y };
+        let right = if self.right() < other.right() {
+            self.right()
+        } else {
+            other.right()
+        };
+        let bottom = if self.bottom() < other.bottom() {
+            self.bottom()
+        } else {
+            other.bottom()
+        };
+
+        if right <= x as i64 || bottom <= y as i64 {
+            return None;
+        }
+        Some(Self::new(
+            x,
+            y,
+            saturate(right - x as i64),
+            saturate(bottom - y as i64),
+        ))
+    }
+    ///The smallest rect that covers both. Empty rects are ignored.
+    pub const fn union(self, other: IRect<U>) -> Self {
+        if other.is_empty() {
+            return self;
+        }
+        if self.is_empty() {
+            return other;
+        }
+
+        let x = if self.x < other.x { self.x } else { other.x };
+        let y = if self.y < other.y { self.y } else { other.y };
+        let right = if self.right() > other.right() {
+            self.right()
+        } else {
+            other.right()
+        };
+        let bottom = if self.bottom() > other.bottom() {
+            self.bottom()
+        } else {
+            other.bottom()
+        };
+        Self::new(
+            x,
+            y,
+            saturate(right - x as i64),
+            saturate(bottom - y as i64),
+        )
+    }
+    ///Moves the rect inside of `area`, shrinking it if it doesn't fit.
+    pub const fn clamp(self, area: IRect<U>) -> Self {
+        let width = if self.width < area.width {
+            self.width
+        } else {
+            area.width
+        };
+        let height = if self.height < area.height {
+            self.height
+        } else {
+            area.height
+        };
+        let max_x = saturate(area.right() - width as i64);
+        let max_y = saturate(area.bottom() - height as i64);
+        let x = if self.x > max_x {
+            max_x
+        } else if self.x < area.x {
+            area.x
+        } else {
+            self.x
+        };
+        let y = if self.y > max_y {
+            max_y
+        } else if self.y < area.y {
+            area.y
+        } else {
+            self.y
+        };
+        Self::new(x, y, width, height)
+    }
+}
+
+///`RECT` stores edges, the width and height wrap so every `RECT` converts back exactly.
+///
+///```
+///# use window::*;
+///let rect = IRect::<Physical>::new(-1920, -200, 1920, 1080);
+///assert_eq!(IRect::from(RECT::from(rect)), rect);
+///```
+impl From<RECT> for IRect<Physical> {
+    fn from(rect: RECT) -> Self {
+        Self::new(
+            rect.left,
+            rect.top,
+            rect.right.wrapping_sub(rect.left),
+            rect.bottom.wrapping_sub(rect.top),
+        )
+    }
+}
+
+impl From<IRect<Physical>> for RECT {
+    fn from(rect: IRect<Physical>) -> Self {
+        RECT {
+            left: rect.x,
+            top: rect.y,
+            right: rect.x.wrapping_add(rect.width),
+            bottom: rect.y.wrapping_add(rect.height),
+        }
+    }
+}
+
+impl From<POINT> for Point<Physical> {
+    fn from(point: POINT) -> Self {
+        Self::new(point.x, point.y)
+    }
+}
+
+impl From<Point<Physical>> for POINT {
+    fn from(point: Point<Physical>) -> Self {
+        POINT {
+            x: point.x,
+            y: point.y,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    ///Small enough that unions and offsets don't saturate.
+    fn coord() -> impl Strategy<Value = i32> {
+        -100_000..100_000
+    }
+
+    fn irect() -> impl Strategy<Value = IRect> {
+        (coord(), coord(), 0..100_000, 0..100_000).prop_map(|(x, y, w, h)| IRect::new(x, y, w, h))
+    }
+
+    fn any_irect() -> impl Strategy<Value = IRect> {
+        any::<(i32, i32, i32, i32)>().prop_map(|(x, y, w, h)| IRect::new(x, y, w, h))
+    }
+
+    fn point() -> impl Strategy<Value = Point> {
+        (coord(), coord()).prop_map(|(x, y)| Point::new(x, y))
+    }
+
+    proptest! {
+        #[test]
+        fn rect_roundtrip(left: i32, top: i32, right: i32, bottom: i32) {
+            let rect = RECT { left, top, right, bottom };
+            let back = RECT::from(IRect::from(rect));
+            prop_assert_eq!(
+                (back.left, back.top, back.right, back.bottom),
+                (left, top, right, bottom)
+            );
+        }
+
+        #[test]
+        fn irect_roundtrip(rect in any_irect()) {
+            prop_assert_eq!(IRect::from(RECT::from(rect)), rect);
+        }
+
+        #[test]
+        fn point_roundtrip(x: i32, y: i32) {
+            let point = POINT::from(Point::new(x, y));
+            prop_assert_eq!((point.x, point.y), (x, y));
+            prop_assert_eq!(Point::from(point), Point::new(x, y));
+        }
+
+        #[test]
+        fn intersection(a in any_irect(), b in any_irect(), p in any::<(i32, i32)>()) {
+            let p = Point::new(p.0, p.1);
+            let both = a.contains(p) && b.contains(p);
+            match a.intersection(b) {
+                Some(i) => {
+                    prop_assert!(!i.is_empty());
+                    prop_assert_eq!(i.contains(p), both);
+                    prop_assert_eq!(b.intersection(a), Some(i));
+                    prop_assert_eq!(i.intersection(a), Some(i));
+                }
+                None => prop_assert!(!both),
+            }
+        }
+
+        #[test]
+        fn union(a in irect(), b in irect(), p in point()) {
+            let u = a.union(b);
+            prop_assert_eq!(u, b.union(a));
+            if a.contains(p) || b.contains(p) {
+                prop_assert!(u.contains(p));
+            }
+            if !a.is_empty() && !b.is_empty() {
+                prop_assert_eq!(u.x, a.x.min(b.x));
+                prop_assert_eq!(u.y, a.y.min(b.y));
+                prop_assert_eq!(u.right(), a.right().max(b.right()));
+                prop_assert_eq!(u.bottom(), a.bottom().max(b.bottom()));
+            }
+        }
+
+        #[test]
+        fn clamp(rect in irect(), area in irect()) {
+            let clamped = rect.clamp(area);
+            prop_assert_eq!(clamped.width, rect.width.min(area.width));
+            prop_assert_eq!(clamped.height, rect.height.min(area.height));
+            prop_assert!(clamped.x >= area.x && clamped.right() <= area.right());
+            prop_assert!(clamped.y >= area.y && clamped.bottom() <= area.bottom());
+            //Already inside, nothing moves.
+            if rect.intersection(area) == Some(rect) {
+                prop_assert_eq!(clamped, rect);
+            }
+        }
+
+        #[test]
+        fn clamp_point(p in point(), area in irect()) {
+            let clamped = p.clamp(area);
+            if area.is_empty() {
+                prop_assert!(clamped.x == area.x || clamped.y == area.y);
+            } else {
+                prop_assert!(area.contains(clamped));
+                if area.contains(p) {
+                    prop_assert_eq!(clamped, p);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,438 @@
+use crate::*;
+use std::f32::consts::PI;
+
+///Distance in pixels the fingers need to move before a gesture starts.
+///Prevents small jitters from being reported as a pan or pinch.
+pub const GESTURE_SLOP: f32 = 8.0;
+
+///Contacts that haven't been updated in this many milliseconds are dropped.
+///`WM_POINTERUP` is not always delivered, for example when focus changes mid gesture.
+pub const STALE_TOUCH_MS: u32 = 500;
+
+///One notch of a mouse wheel.
+pub const WHEEL_DELTA: i16 = 120;
+
+///Each wheel notch zooms by 10%.
+pub const WHEEL_ZOOM_STEP: f32 = 1.1;
+
+///Pixels a touchpad pans for one `WHEEL_DELTA` worth of scrolling.
+pub const WHEEL_PAN_PIXELS: f32 = 100.0;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TouchPoint {
+    pub id: u32,
+    pub phase: TouchPhase,
+    pub x: f32,
+    pub y: f32,
+    ///Milliseconds, usually from `GetMessageTime`. Only the difference between points matters.
+    pub time: u32,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct Contact {
+    id: u32,
+    x: f32,
+    y: f32,
+    time: u32,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct Shape {
+    x: f32,
+    y: f32,
+    ///Distance between the first two contacts.
+    distance: f32,
+    ///Angle between the first two contacts.
+    angle: f32,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+enum State {
+    #[default]
+    Idle,
+    ///Fingers are down but haven't moved past `GESTURE_SLOP`.
+    Possible {
+        start: Shape,
+    },
+    Active,
+}
+
+///Turns raw touch points into `Event::Pan`, `Event::Pinch` and `Event::Rotate`.
+///
+///This doesn't touch any Windows APIs so it can be driven with recorded or made up input.
+///
+///```
+///# use window::*;
+///let mut gestures = GestureRecognizer::new();
+///let start = TouchPoint { id: 0, phase: TouchPhase::Started, x: 0.0, y: 0.0, time: 0 };
+///assert!(gestures.update(start).is_empty());
+///
+///let moved = TouchPoint { phase: TouchPhase::Moved, x: 20.0, time: 16, ..start };
+///assert_eq!(gestures.update(moved), vec![Event::Pan { dx: 20.0, dy: 0.0 }]);
+///```
+#[derive(Debug, Clone, Default)]
+pub struct GestureRecognizer {
+    contacts: Vec<Contact>,
+    state: State,
+}
+
+impl GestureRecognizer {
+    pub const fn new() -> Self {
+        Self {
+            contacts: Vec::new(),
+            state: State::Idle,
+        }
+    }
+    ///Number of fingers currently down.
+    pub fn touches(&self) -> usize {
+        self.contacts.len()
+    }
+    pub fn is_active(&self) -> bool {
+        self.state == State::Active
+    }
+    pub fn reset(&mut self) {
+        self.contacts.clear();
+        self.state = State::Idle;
+    }
+    pub fn update(&mut self, point: TouchPoint) -> Vec<Event> {
+        self.contacts
+            .retain(|c| c.id == point.id || point.time.wrapping_sub(c.time) <= STALE_TOUCH_MS);
+
+        let before = self.shape();
+        let count = self.contacts.len();
+
+        match point.phase {
+            TouchPhase::Started | TouchPhase::Moved => {
+                let contact = Contact {
+                    id: point.id,
+                    x: point.x,
+                    y: point.y,
+                    time: point.time,
+                };
+                match self.contacts.iter_mut().find(|c| c.id == point.id) {
+                    Some(c) => *c = contact,
+                    None => self.contacts.push(contact),
+                }
+            }
+            TouchPhase::Ended | TouchPhase::Cancelled => {
+                self.contacts.retain(|c| c.id != point.id);
+            }
+        }
+
+        let Some(after) = self.shape() else {
+            self.state = State::Idle;
+            return Vec::new();
+        };
+
+        //Adding or removing a finger moves the centroid, which isn't real movement.
+        //Start measuring again from the new shape.
+        let Some(before) = before.filter(|_| count == self.contacts.len()) else {
+            if self.state != State::Active {
+                self.state = State::Possible { start: after };
+            }
+            return Vec::new();
+        };
+
+        if let State::Possible { start } = self.state {
+            let moved = (after.x - start.x).hypot(after.y - start.y);
+            let spread = (after.distance - start.distance).abs();
+            let twist = wrap_angle(after.angle - start.angle).abs() * after.distance / 2.0;
+
+            if moved.max(spread).max(twist) < GESTURE_SLOP {
+                return Vec::new();
+            }
+
+            self.state = State::Active;
+            //Report everything since the fingers went down, not just the last move.
+            return gesture_events(start, after, self.contacts.len());
+        }
+
+        if self.state == State::Active {
+            gesture_events(before, after, self.contacts.len())
+        } else {
+            Vec::new()
+        }
+    }
+    ///Centroid of all contacts. Distance and angle only use the first two.
+    fn shape(&self) -> Option<Shape> {
+        let n = self.contacts.len() as f32;
+        let first = self.contacts.first()?;
+        let x = self.contacts.iter().map(|c| c.x).sum::<f32>() / n;
+        let y = self.contacts.iter().map(|c| c.y).sum::<f32>() / n;
+
+        let (distance, angle) = match self.contacts.get(1) {
+            Some(second) => {
+                let (dx, dy) = (second.x - first.x, second.y - first.y);
+                (dx.hypot(dy), dy.atan2(dx))
+            }
+            None => (0.0, 0.0),
+        };
+
+        Some(Shape {
+            x,
+            y,
+            distance,
+            angle,
+        })
+    }
+}
+
+fn gesture_events(before: Shape, after: Shape, touches: usize) -> Vec<Event> {
+    let mut events = Vec::new();
+
+    let (dx, dy) = (after.x - before.x, after.y - before.y);
+    if dx != 0.0 || dy != 0.0 {
+        events.push(Event::Pan { dx, dy });
+    }
+
+    if touches >= 2 && before.distance > 0.0 {
+        let scale = after.distance / before.distance;
+        if scale != 1.0 {
+            events.push(Event::Pinch { scale });
+        }
+
+        let radians = wrap_angle(after.angle - before.angle);
+        if radians != 0.0 {
+            events.push(Event::Rotate { radians });
+        }
+    }
+
+    events
+}
+
+///Keeps the angle between -PI and PI so crossing the negative x axis isn't a full turn.
+fn wrap_angle(radians: f32) -> f32 {
+    if radians > PI {
+        radians - 2.0 * PI
+    } else if radians < -PI {
+        radians + 2.0 * PI
+    } else {
+        radians
+    }
+}
+
+///Precision touchpads send pinch as `WM_MOUSEWHEEL` with control held
+///and two finger scrolling as regular wheel messages.
+///
+///Regular mice scroll in whole notches of `WHEEL_DELTA`, those are left to `Key::ScrollUp`
+///and `Key::ScrollDown` and don't pan. Touchpads send smaller deltas which are converted
+///to pixels with `WHEEL_PAN_PIXELS`.
+///
+///Pans move the content the same way a touch drag would. Scrolling up or left moves
+///the content down or right, so `dy` has the sign of `WM_MOUSEWHEEL` and `dx` is the
+///opposite of `WM_MOUSEHWHEEL`.
+pub fn wheel_gesture(delta: i16, control: bool, horizontal: bool) -> Option<Event> {
+    let notches = delta as f32 / WHEEL_DELTA as f32;
+
+    if control && !horizontal {
+        return Some(Event::Pinch {
+            scale: WHEEL_ZOOM_STEP.powf(notches),
+        });
+    }
+
+    if delta % WHEEL_DELTA == 0 {
+        return None;
+    }
+
+    let pixels = notches * WHEEL_PAN_PIXELS;
+    if horizontal {
+        Some(Event::Pan {
+            dx: -pixels,
+            dy: 0.0,
+        })
+    } else {
+        Some(Event::Pan {
+            dx: 0.0,
+            dy: pixels,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::FRAC_PI_2;
+
+    fn touch(id: u32, phase: TouchPhase, x: f32, y: f32, time: u32) -> TouchPoint {
+        TouchPoint {
+            id,
+            phase,
+            x,
+            y,
+            time,
+        }
+    }
+
+    fn down(gestures: &mut GestureRecognizer, id: u32, x: f32, y: f32, time: u32) -> Vec<Event> {
+        gestures.update(touch(id, TouchPhase::Started, x, y, time))
+    }
+
+    fn drag(gestures: &mut GestureRecognizer, id: u32, x: f32, y: f32, time: u32) -> Vec<Event> {
+        gestures.update(touch(id, TouchPhase::Moved, x, y, time))
+    }
+
+    fn up(gestures: &mut GestureRecognizer, id: u32, time: u32) -> Vec<Event> {
+        gestures.update(touch(id, TouchPhase::Ended, 0.0, 0.0, time))
+    }
+
+    fn close(a: f32, b: f32) -> bool {
+        (a - b).abs() < 1e-4
+    }
+
+    #[test]
+    fn slop() {
+        let mut g = GestureRecognizer::new();
+        assert!(down(&mut g, 0, 0.0, 0.0, 0).is_empty());
+        assert!(drag(&mut g, 0, 3.0, 4.0, 10).is_empty());
+        assert!(drag(&mut g, 0, -4.0, 5.0, 20).is_empty());
+        assert!(!g.is_active());
+
+        //Everything since the finger went down is reported once the slop is passed.
+        let events = drag(&mut g, 0, 8.0, 0.0, 30);
+        assert_eq!(events, vec![Event::Pan { dx: 8.0, dy: 0.0 }]);
+        assert!(g.is_active());
+    }
+
+    #[test]
+    fn pan() {
+        let mut g = GestureRecognizer::new();
+        down(&mut g, 0, 10.0, 10.0, 0);
+        assert_eq!(
+            drag(&mut g, 0, 30.0, 10.0, 10),
+            vec![Event::Pan { dx: 20.0, dy: 0.0 }]
+        );
+        assert_eq!(
+            drag(&mut g, 0, 25.0, 15.0, 20),
+            vec![Event::Pan { dx: -5.0, dy: 5.0 }]
+        );
+        //No movement, no event.
+        assert!(drag(&mut g, 0, 25.0, 15.0, 30).is_empty());
+
+        assert!(up(&mut g, 0, 40).is_empty());
+        assert_eq!(g.touches(), 0);
+        assert!(!g.is_active());
+    }
+
+    #[test]
+    fn pinch() {
+        let mut g = GestureRecognizer::new();
+        down(&mut g, 0, 0.0, 0.0, 0);
+        down(&mut g, 1, 100.0, 0.0, 0);
+
+        let events = drag(&mut g, 1, 200.0, 0.0, 10);
+        assert!(events.contains(&Event::Pinch { scale: 2.0 }), "{events:?}");
+        assert!(!events.iter().any(|e| matches!(e, Event::Rotate { .. })));
+
+        let events = drag(&mut g, 1, 100.0, 0.0, 20);
+        assert!(events.contains(&Event::Pinch { scale: 0.5 }), "{events:?}");
+    }
+
+    #[test]
+    fn rotate() {
+        let mut g = GestureRecognizer::new();
+        down(&mut g, 0, 0.0, 0.0, 0);
+        down(&mut g, 1, 100.0, 0.0, 0);
+
+        let events = drag(&mut g, 1, 0.0, 100.0, 10);
+        assert!(
+            events
+                .iter()
+                .any(|e| matches!(e, Event::Rotate { radians } if close(*radians, FRAC_PI_2))),
+            "{events:?}"
+        );
+        //Same distance, so no pinch.
+        assert!(!events.iter().any(|e| matches!(e, Event::Pinch { .. })));
+
+        //Crossing the negative x axis is a small turn, not almost a full one.
+        let mut g = GestureRecognizer::new();
+        down(&mut g, 0, 0.0, 0.0, 0);
+        down(&mut g, 1, -100.0, 1.0, 0);
+        let events = drag(&mut g, 1, -100.0, -20.0, 10);
+        let radians = events
+            .iter()
+            .find_map(|e| match e {
+                Event::Rotate { radians } => Some(*radians),
+                _ => None,
+            })
+            .unwrap();
+        assert!(radians.abs() < 0.5, "{radians}");
+    }
+
+    #[test]
+    fn add_and_remove_fingers() {
+        let mut g = GestureRecognizer::new();
+        down(&mut g, 0, 0.0, 0.0, 0);
+        drag(&mut g, 0, 20.0, 0.0, 10);
+        assert!(g.is_active());
+
+        //The centroid jumps to (60, 0) but nothing moved.
+        assert!(down(&mut g, 1, 100.0, 0.0, 20).is_empty());
+        assert_eq!(g.touches(), 2);
+        assert!(g.is_active());
+
+        assert_eq!(
+            drag(&mut g, 0, 30.0, 0.0, 30),
+            vec![
+                Event::Pan { dx: 5.0, dy: 0.0 },
+                Event::Pinch { scale: 0.875 }
+            ]
+        );
+
+        //Lifting a finger doesn't pan either.
+        assert!(up(&mut g, 1, 40).is_empty());
+        assert_eq!(g.touches(), 1);
+        assert_eq!(
+            drag(&mut g, 0, 40.0, 0.0, 50),
+            vec![Event::Pan { dx: 10.0, dy: 0.0 }]
+        );
+
+        assert!(up(&mut g, 0, 60).is_empty());
+        assert!(!g.is_active());
+    }
+
+    #[test]
+    fn stale_contacts() {
+        let mut g = GestureRecognizer::new();
+        down(&mut g, 0, 0.0, 0.0, 0);
+        down(&mut g, 1, 100.0, 0.0, 0);
+        assert_eq!(g.touches(), 2);
+
+        //Contact 1 never got a `WM_POINTERUP`.
+        drag(&mut g, 0, 0.0, 0.0, STALE_TOUCH_MS);
+        assert_eq!(g.touches(), 2);
+        drag(&mut g, 0, 0.0, 0.0, STALE_TOUCH_MS + 1);
+        assert_eq!(g.touches(), 1);
+
+        //Message time wraps after 49.7 days.
+        let mut g = GestureRecognizer::new();
+        down(&mut g, 0, 0.0, 0.0, u32::MAX - 10);
+        down(&mut g, 1, 0.0, 0.0, 10);
+        assert_eq!(g.touches(), 2);
+    }
+
+    #[test]
+    fn wheel() {
+        assert_eq!(wheel_gesture(WHEEL_DELTA, false, false), None);
+        assert_eq!(wheel_gesture(-WHEEL_DELTA * 2, false, true), None);
+        assert_eq!(
+            wheel_gesture(WHEEL_DELTA / 2, false, false),
+            Some(Event::Pan {
+                dx: 0.0,
+                dy: WHEEL_PAN_PIXELS / 2.0
+            })
+        );
+        assert_eq!(
+            wheel_gesture(WHEEL_DELTA / 2, false, true),
+            Some(Event::Pan {
+                dx: -WHEEL_PAN_PIXELS / 2.0,
+                dy: 0.0
+            })
+        );
+        assert_eq!(
+            wheel_gesture(WHEEL_DELTA, true, false),
+            Some(Event::Pinch {
+                scale: WHEEL_ZOOM_STEP
+            })
+        );
+    }
+}
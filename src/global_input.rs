@@ -89,6 +89,19 @@ pub unsafe extern "system" fn mouse_proc(code: i32, w_param: usize, l_param: isi
     CallNextHookEx(HOOK, code, w_param, l_param)
 }
 
+///Only thread messages posted by `mouse_proc`, their `l_param` is a `MSLLHOOKSTRUCT`.
+fn is_hook_message(msg: &MSG) -> bool {
+    msg.hwnd == 0 && (USER_MOUSEWHEEL..=USER_XBUTTONDBLCLK).contains(&msg.message)
+}
+
+///Anything else on the thread queue still belongs to a window, like wake ups from an `EventProxy`.
+unsafe fn dispatch_window_message(msg: &MSG, result: i32) {
+    if result > 0 && msg.hwnd != 0 {
+        TranslateMessage(msg);
+        DispatchMessageW(msg);
+    }
+}
+
 pub fn poll_global_events() -> Option<Event> {
     unsafe {
         ONCE.call_once(|| {
@@ -98,13 +111,12 @@ pub fn poll_global_events() -> Option<Event> {
         });
 
         let mut msg: MSG = core::mem::zeroed();
-        let result = PeekMessageA(&mut msg, 0, 0, 0, PM_REMOVE);
+        let result = PeekMessageW(&mut msg, 0, 0, 0, PM_REMOVE);
 
-        if msg.message > WM_USER {
+        if is_hook_message(&msg) {
             handle_mouse_msg(msg, result)
         } else {
-            // TranslateMessage(&msg);
-            // DispatchMessageA(&msg);
+            dispatch_window_message(&msg, result);
             None
         }
     }
@@ -119,11 +131,12 @@ pub fn wait_for_global_events() -> Option<Event> {
         });
 
         let mut msg: MSG = core::mem::zeroed();
-        let result = GetMessageA(&mut msg, 0, 0, 0);
+        let result = GetMessageW(&mut msg, 0, 0, 0);
 
-        if msg.message > WM_USER {
+        if is_hook_message(&msg) {
             handle_mouse_msg(msg, result)
         } else {
+            dispatch_window_message(&msg, result);
             None
         }
     }
@@ -215,7 +228,7 @@ pub fn handle_mouse_msg(msg: MSG, result: i32) -> Option<Event> {
     match result {
         -1 => {
             let last_error = unsafe { GetLastError() };
-            panic!("Error with `GetMessageA`, error code: {}", last_error);
+            panic!("Error with `GetMessageW`, error code: {}", last_error);
         }
         0 => return None,
         _ => {}
@@ -0,0 +1,72 @@
+use crate::*;
+
+///What part of the window is under the cursor.
+///Returned from `WM_NCHITTEST` so borderless windows can be moved, snapped and resized natively.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum HitTest {
+    Client,
+    ///Dragging moves the window, double clicking maximizes it.
+    Caption,
+    Close,
+    Minimize,
+    ///Also shows the snap layout flyout on Windows 11.
+    Maximize,
+    Left,
+    Right,
+    Top,
+    Bottom,
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+impl HitTest {
+    pub const fn code(self) -> isize {
+        match self {
+            HitTest::Client => HTCLIENT,
+            HitTest::Caption => HTCAPTION,
+            HitTest::Close => HTCLOSE,
+            HitTest::Minimize => HTMINBUTTON,
+            HitTest::Maximize => HTMAXBUTTON,
+            HitTest::Left => HTLEFT,
+            HitTest::Right => HTRIGHT,
+            HitTest::Top => HTTOP,
+            HitTest::Bottom => HTBOTTOM,
+            HitTest::TopLeft => HTTOPLEFT,
+            HitTest::TopRight => HTTOPRIGHT,
+            HitTest::BottomLeft => HTBOTTOMLEFT,
+            HitTest::BottomRight => HTBOTTOMRIGHT,
+        }
+    }
+    ///Which resize edge `(x, y)` is on, if any. `border` is the thickness of the edges.
+    pub const fn edge(x: i32, y: i32, width: i32, height: i32, border: i32) -> Option<HitTest> {
+        let left = x < border;
+        let right = x >= width - border;
+        let top = y < border;
+        let bottom = y >= height - border;
+
+        match (left, right, top, bottom) {
+            (true, _, true, _) => Some(HitTest::TopLeft),
+            (_, true, true, _) => Some(HitTest::TopRight),
+            (true, _, _, true) => Some(HitTest::BottomLeft),
+            (_, true, _, true) => Some(HitTest::BottomRight),
+            (true, _, _, _) => Some(HitTest::Left),
+            (_, true, _, _) => Some(HitTest::Right),
+            (_, _, true, _) => Some(HitTest::Top),
+            (_, _, _, true) => Some(HitTest::Bottom),
+            _ => None,
+        }
+    }
+}
+
+///Decides what's under the cursor, `(x, y)` are in client coordinates.
+///Return `None` to fall back to the regions and resize border.
+pub type HitTestCallback = fn(window: &Window, x: i32, y: i32) -> Option<HitTest>;
+
+#[repr(C)]
+#[derive(Debug)]
+pub struct NCCALCSIZE_PARAMS {
+    pub rgrc: [RECT; 3],
+    pub lppos: *mut c_void,
+}
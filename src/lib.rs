@@ -14,24 +14,52 @@ mod clipboard;
 mod constants;
 mod dark_theme;
 mod debug;
+mod drag_drop;
 mod event;
+mod event_loop;
 mod fps;
+mod gamepad;
 mod gdi;
+mod geometry;
+mod gesture;
 mod global_input;
+mod hit_test;
 mod input;
 mod monitor;
+mod monitor_watcher;
+mod placement;
+mod pointer;
+mod proxy;
+mod style;
+mod taskbar;
+mod wait;
+mod wide;
 mod window;
 
 pub use clipboard::*;
 pub use constants::*;
 pub use dark_theme::*;
 pub use debug::*;
+pub use drag_drop::*;
 pub use event::*;
+pub use event_loop::*;
 pub use fps::*;
+pub use gamepad::*;
 pub use gdi::*;
+pub use geometry::*;
+pub use gesture::*;
 pub use global_input::*;
+pub use hit_test::*;
 pub use input::*;
 pub use monitor::*;
+pub use monitor_watcher::*;
+pub use placement::*;
+pub use pointer::*;
+pub use proxy::*;
+pub use style::*;
+pub use taskbar::*;
+pub use wait::*;
+pub use wide::*;
 pub use window::*;
 
 pub type BYTE = u8;
@@ -53,10 +81,10 @@ pub type LPWSTR = *mut u16;
 
 #[link(name = "user32")]
 extern "system" {
-    pub fn CreateWindowExA(
+    pub fn CreateWindowExW(
         dwexstyle: u32,
-        lpclassname: *const u8,
-        lpwindowname: *const u8,
+        lpclassname: LPCWSTR,
+        lpwindowname: LPCWSTR,
         dwstyle: u32,
         x: i32,
         y: i32,
@@ -67,18 +95,19 @@ extern "system" {
         hinstance: isize,
         lpparam: *const std::ffi::c_void,
     ) -> isize;
-    pub fn PeekMessageA(
+    pub fn PeekMessageW(
         msg: *mut MSG,
         hwnd: isize,
         msg_filter_min: u32,
         msg_filter_max: u32,
         remove_msg: u32,
     ) -> i32;
-    pub fn GetMessageA(msg: *mut MSG, hwnd: isize, msg_filter_min: u32, msg_filter_max: u32)
+    pub fn GetMessageW(msg: *mut MSG, hwnd: isize, msg_filter_min: u32, msg_filter_max: u32)
         -> i32;
     pub fn PostQuitMessage(nExitCode: i32);
-    pub fn RegisterClassA(lpwndclass: *const WNDCLASSA) -> u16;
-    pub fn DispatchMessageA(lpMsg: *const MSG) -> isize;
+    pub fn RegisterClassW(lpwndclass: *const WNDCLASSW) -> u16;
+    pub fn UnregisterClassW(lpClassName: LPCWSTR, hInstance: isize) -> BOOL;
+    pub fn DispatchMessageW(lpMsg: *const MSG) -> isize;
     ///Translates virtual-key messages into character messages.
     pub fn TranslateMessage(lpMsg: *const MSG) -> i32;
     pub fn GetLastError() -> u32;
@@ -98,9 +127,11 @@ extern "system" {
     pub fn GetKeyState(nVirtKey: i32) -> i16;
     pub fn GetCursorPos(point: *mut POINT) -> i32;
     pub fn GetPhysicalCursorPos(point: *mut POINT) -> i32;
-    pub fn DefWindowProcA(hwnd: isize, msg: u32, wparam: usize, lparam: isize) -> isize;
+    pub fn DefWindowProcW(hwnd: isize, msg: u32, wparam: usize, lparam: isize) -> isize;
     pub fn GetWindow(hwnd: isize, uCmd: u32) -> isize;
+    pub fn GetClassNameW(hwnd: isize, lpClassName: *mut u16, nMaxCount: i32) -> i32;
     pub fn DestroyWindow(hwnd: isize) -> i32;
+    pub fn IsWindow(hwnd: isize) -> BOOL;
     pub fn GetForegroundWindow() -> isize;
     pub fn GetWindowLongPtrW(hwnd: isize, nIndex: i32) -> isize;
     pub fn SetWindowLongPtrW(hwnd: isize, nIndex: i32, dwNewLong: isize) -> isize;
@@ -109,8 +140,28 @@ extern "system" {
     pub fn GetWindowLongA(hwnd: isize, nIndex: i32) -> LONG;
     pub fn SetWindowLongA(hwnd: isize, nIndex: i32, dwNewLong: LONG) -> LONG;
     pub fn ShowWindow(hwnd: isize, nCmdShow: i32) -> BOOL;
+    pub fn EnableWindow(hwnd: isize, bEnable: BOOL) -> BOOL;
+    pub fn SetTimer(
+        hwnd: isize,
+        nIDEvent: usize,
+        uElapse: u32,
+        lpTimerFunc: *const c_void,
+    ) -> usize;
+    pub fn KillTimer(hwnd: isize, uIDEvent: usize) -> BOOL;
+    pub fn EnumThreadWindows(
+        dwThreadId: u32,
+        lpfn: Option<unsafe extern "system" fn(hwnd: isize, lparam: isize) -> BOOL>,
+        lParam: isize,
+    ) -> BOOL;
     pub fn GetWindowInfo(hwnd: isize, pwi: *mut WindowInfo) -> i32;
     pub fn AdjustWindowRectEx(lpRect: *mut RECT, dwStyle: u32, bMenu: i32, dwExStyle: u32) -> i32;
+    pub fn AdjustWindowRectExForDpi(
+        lpRect: *mut RECT,
+        dwStyle: u32,
+        bMenu: i32,
+        dwExStyle: u32,
+        dpi: u32,
+    ) -> i32;
     pub fn GetDesktopWindow() -> isize;
     pub fn GetWindowRect(hwnd: isize, lpRect: *mut RECT) -> i32;
     pub fn GetClientRect(hwnd: isize, lpRect: *mut RECT) -> i32;
@@ -141,6 +192,17 @@ extern "system" {
     ) -> i32;
 
     pub fn SetLayeredWindowAttributes(hwnd: isize, color_key: u32, alpha: u8, flags: u32) -> i32;
+    pub fn UpdateLayeredWindow(
+        hwnd: isize,
+        hdcDst: *mut c_void,
+        pptDst: *const POINT,
+        psize: *const SIZE,
+        hdcSrc: *mut c_void,
+        pptSrc: *const POINT,
+        crKey: u32,
+        pblend: *const BLENDFUNCTION,
+        dwFlags: u32,
+    ) -> i32;
 
     pub fn GetSystemMetricsForDpi(nIndex: i32, dpi: u32) -> i32;
 
@@ -151,6 +213,18 @@ extern "system" {
 
     pub fn GetDpiForWindow(hwnd: isize) -> u32;
     pub fn ReleaseCapture() -> i32;
+    pub fn SetCapture(hwnd: isize) -> isize;
+    pub fn GetCapture() -> isize;
+    pub fn TrackMouseEvent(lpEventTrack: *mut TRACKMOUSEEVENT) -> BOOL;
+    pub fn GetMessageTime() -> LONG;
+    pub fn GetWindowPlacement(hwnd: isize, lpwndpl: *mut WINDOWPLACEMENT) -> BOOL;
+    pub fn SetWindowPlacement(hwnd: isize, lpwndpl: *const WINDOWPLACEMENT) -> BOOL;
+    pub fn IsZoomed(hwnd: isize) -> BOOL;
+    pub fn IsIconic(hwnd: isize) -> BOOL;
+    pub fn IsWindowVisible(hwnd: isize) -> BOOL;
+    pub fn SetForegroundWindow(hwnd: isize) -> BOOL;
+    pub fn SetFocus(hwnd: isize) -> isize;
+    pub fn FlashWindowEx(pfwi: *const FLASHWINFO) -> BOOL;
 }
 
 #[repr(C)]
@@ -221,33 +295,40 @@ impl Rect {
         self.height = height;
         self
     }
+    ///Saturates instead of overflowing, like the rest of `Rect`.
     pub const fn right(&self) -> usize {
-        self.x + self.width
+        self.x.saturating_add(self.width)
     }
     pub const fn bottom(&self) -> usize {
-        self.y + self.height
+        self.y.saturating_add(self.height)
+    }
+    pub const fn contains(&self, x: usize, y: usize) -> bool {
+        x >= self.x && x < self.right() && y >= self.y && y < self.bottom()
     }
     pub const fn intersects(&self, other: Rect) -> bool {
-        self.x < other.x + other.width
-            && self.x + self.width > other.x
-            && self.y < other.y + other.height
-            && self.y + self.height > other.y
+        self.x < other.right()
+            && self.right() > other.x
+            && self.y < other.bottom()
+            && self.bottom() > other.y
     }
-    //TODO: Bounds checking
+    ///Shrinks the rect by `w` and `h` on each side, down to zero.
     pub const fn inner(&self, w: usize, h: usize) -> Rect {
         Rect {
-            x: self.x + w,
-            y: self.y + h,
-            width: self.width - 2 * w,
-            height: self.height - 2 * h,
+            x: self.x.saturating_add(w),
+            y: self.y.saturating_add(h),
+            width: self.width.saturating_sub(w.saturating_mul(2)),
+            height: self.height.saturating_sub(h.saturating_mul(2)),
         }
     }
+    ///Negative positions can't be stored, they become zero. See `IRect` for screen coordinates.
     pub const fn from_windows(rect: RECT) -> Rect {
+        let width = rect.right as i64 - rect.left as i64;
+        let height = rect.bottom as i64 - rect.top as i64;
         Rect {
-            x: 0,
-            y: 0,
-            width: (rect.right - rect.left) as usize,
-            height: (rect.bottom - rect.top) as usize,
+            x: if rect.left > 0 { rect.left as usize } else { 0 },
+            y: if rect.top > 0 { rect.top as usize } else { 0 },
+            width: if width > 0 { width as usize } else { 0 },
+            height: if height > 0 { height as usize } else { 0 },
         }
     }
 }
@@ -285,6 +366,70 @@ impl MSG {
     }
 }
 
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct TRACKMOUSEEVENT {
+    pub cbSize: u32,
+    pub dwFlags: u32,
+    pub hwndTrack: isize,
+    pub dwHoverTime: u32,
+}
+
+impl TRACKMOUSEEVENT {
+    pub const fn new(hwnd: isize, flags: u32) -> Self {
+        Self {
+            cbSize: size_of::<Self>() as u32,
+            dwFlags: flags,
+            hwndTrack: hwnd,
+            dwHoverTime: 0,
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct WINDOWPLACEMENT {
+    pub length: u32,
+    pub flags: u32,
+    pub showCmd: u32,
+    pub ptMinPosition: POINT,
+    pub ptMaxPosition: POINT,
+    pub rcNormalPosition: RECT,
+}
+
+impl Default for WINDOWPLACEMENT {
+    fn default() -> Self {
+        Self {
+            length: size_of::<Self>() as u32,
+            flags: 0,
+            showCmd: 0,
+            ptMinPosition: POINT::default(),
+            ptMaxPosition: POINT::default(),
+            rcNormalPosition: RECT::default(),
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Clone)]
+pub struct MINMAXINFO {
+    pub ptReserved: POINT,
+    pub ptMaxSize: POINT,
+    pub ptMaxPosition: POINT,
+    pub ptMinTrackSize: POINT,
+    pub ptMaxTrackSize: POINT,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct FLASHWINFO {
+    pub cbSize: u32,
+    pub hwnd: isize,
+    pub dwFlags: u32,
+    pub uCount: u32,
+    pub dwTimeout: u32,
+}
+
 #[repr(C)]
 #[derive(Debug, Default, Clone)]
 pub struct WindowInfo {
@@ -302,7 +447,7 @@ pub struct WindowInfo {
 
 #[repr(C)]
 #[derive(Debug, Clone)]
-pub struct WNDCLASSA {
+pub struct WNDCLASSW {
     pub style: u32,
     pub wnd_proc: Option<
         unsafe extern "system" fn(hwnd: isize, msg: u32, wparam: usize, lparam: isize) -> isize,
@@ -313,8 +458,8 @@ pub struct WNDCLASSA {
     pub icon: isize,
     pub cursor: isize,
     pub background: isize,
-    pub menu_name: *const u8,
-    pub class_name: *const u8,
+    pub menu_name: LPCWSTR,
+    pub class_name: LPCWSTR,
 }
 
 pub trait LowHighOrder {
@@ -345,3 +490,54 @@ impl LowHighOrder for u32 {
         (self >> 16) & 0xffff
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn rect_inner(x: usize, y: usize, width: usize, height: usize, w: usize, h: usize) {
+            let rect = Rect { x, y, width, height };
+            let inner = rect.inner(w, h);
+            prop_assert!(inner.width <= width && inner.height <= height);
+            if width < w.saturating_mul(2) {
+                prop_assert_eq!(inner.width, 0);
+            }
+            if height < h.saturating_mul(2) {
+                prop_assert_eq!(inner.height, 0);
+            }
+        }
+
+        #[test]
+        fn rect_from_windows(left: i32, top: i32, right: i32, bottom: i32) {
+            let rect = Rect::from_windows(RECT { left, top, right, bottom });
+            prop_assert_eq!(rect.x, left.max(0) as usize);
+            prop_assert_eq!(rect.y, top.max(0) as usize);
+            prop_assert_eq!(rect.width as i64, (right as i64 - left as i64).max(0));
+            prop_assert_eq!(rect.height as i64, (bottom as i64 - top as i64).max(0));
+        }
+
+        #[test]
+        fn rect_contains(x: usize, y: usize, width: usize, height: usize, px: usize, py: usize) {
+            let rect = Rect { x, y, width, height };
+            let inside = px >= x && px - x < width && py >= y && py - y < height;
+            //Only a rect reaching `usize::MAX` can differ, the last pixel is cut off.
+            if rect.right() < usize::MAX && rect.bottom() < usize::MAX {
+                prop_assert_eq!(rect.contains(px, py), inside);
+            }
+            prop_assert!(!rect.contains(px, py) || inside);
+        }
+
+        #[test]
+        fn rect_intersects(a: (usize, usize, usize, usize), b: (usize, usize, usize, usize)) {
+            let a = Rect { x: a.0, y: a.1, width: a.2, height: a.3 };
+            let b = Rect { x: b.0, y: b.1, width: b.2, height: b.3 };
+            prop_assert_eq!(a.intersects(b), b.intersects(a));
+            if a.intersects(b) {
+                prop_assert!(a.width > 0 && a.height > 0 && b.width > 0 && b.height > 0);
+            }
+        }
+    }
+}
@@ -1,8 +1,18 @@
 use window::*;
 
 fn main() {
-    let mut window = create_window("Window", 0, 0, 600, 400, WindowStyle::DEFAULT);
-    let mut window2 = create_window("Window2", 0, 0, 600, 400, WindowStyle::DEFAULT);
+    let mut window = create_window(
+        "Window",
+        None,
+        LogicalSize::new(600, 400),
+        WindowStyle::DEFAULT,
+    );
+    let mut window2 = create_window(
+        "Window2",
+        None,
+        LogicalSize::new(600, 400),
+        WindowStyle::DEFAULT,
+    );
 
     loop {
         match window2.event() {
@@ -10,7 +20,7 @@ fn main() {
         }
 
         match window.event() {
-            Some(Event::Quit | Event::Input(Key::Escape, _)) => break,
+            Some(Event::Quit | Event::CloseRequested | Event::Input(Key::Escape, _)) => break,
             Some(Event::Input(key, modifiers)) => println!("{:?} {:?}", key, modifiers),
             _ => {}
         }
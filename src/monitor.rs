@@ -42,10 +42,329 @@ impl Default for MONITORINFO {
     }
 }
 
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct MONITORINFOEXW {
+    pub cbSize: u32,
+    pub rcMonitor: RECT,
+    pub rcWork: RECT,
+    pub dwFlags: u32,
+    pub szDevice: [u16; 32],
+}
+
+impl Default for MONITORINFOEXW {
+    fn default() -> Self {
+        Self {
+            cbSize: size_of::<Self>() as u32,
+            rcMonitor: RECT::default(),
+            rcWork: RECT::default(),
+            dwFlags: 0,
+            szDevice: [0; 32],
+        }
+    }
+}
+
+pub const MONITORINFOF_PRIMARY: u32 = 0x00000001;
+
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct DISPLAY_DEVICEW {
+    pub cb: u32,
+    pub DeviceName: [u16; 32],
+    pub DeviceString: [u16; 128],
+    pub StateFlags: u32,
+    pub DeviceID: [u16; 128],
+    pub DeviceKey: [u16; 128],
+}
+
+impl Default for DISPLAY_DEVICEW {
+    fn default() -> Self {
+        let mut device: Self = unsafe { core::mem::zeroed() };
+        device.cb = size_of::<Self>() as u32;
+        device
+    }
+}
+
+pub const CDS_UPDATEREGISTRY: u32 = 0x00000001;
+pub const CDS_TEST: u32 = 0x00000002;
+pub const CDS_FULLSCREEN: u32 = 0x00000004;
+pub const CDS_RESET: u32 = 0x40000000;
+
+pub const DISP_CHANGE_SUCCESSFUL: i32 = 0;
+pub const DISP_CHANGE_RESTART: i32 = 1;
+pub const DISP_CHANGE_FAILED: i32 = -1;
+pub const DISP_CHANGE_BADMODE: i32 = -2;
+
+pub const DM_BITSPERPEL: u32 = 0x00040000;
+pub const DM_PELSWIDTH: u32 = 0x00080000;
+pub const DM_PELSHEIGHT: u32 = 0x00100000;
+pub const DM_DISPLAYFREQUENCY: u32 = 0x00400000;
+
+pub const ENUM_CURRENT_SETTINGS: u32 = u32::MAX;
+pub const ENUM_REGISTRY_SETTINGS: u32 = u32::MAX - 1;
+
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct DEVMODEW {
+    pub dmDeviceName: [u16; 32],
+    pub dmSpecVersion: u16,
+    pub dmDriverVersion: u16,
+    pub dmSize: u16,
+    pub dmDriverExtra: u16,
+    pub dmFields: u32,
+    pub dmPosition: POINT,
+    pub dmDisplayOrientation: u32,
+    pub dmDisplayFixedOutput: u32,
+    pub dmColor: i16,
+    pub dmDuplex: i16,
+    pub dmYResolution: i16,
+    pub dmTTOption: i16,
+    pub dmCollate: i16,
+    pub dmFormName: [u16; 32],
+    pub dmLogPixels: u16,
+    pub dmBitsPerPel: u32,
+    pub dmPelsWidth: u32,
+    pub dmPelsHeight: u32,
+    pub dmDisplayFlags: u32,
+    pub dmDisplayFrequency: u32,
+    pub dmICMMethod: u32,
+    pub dmICMIntent: u32,
+    pub dmMediaType: u32,
+    pub dmDitherType: u32,
+    pub dmReserved1: u32,
+    pub dmReserved2: u32,
+    pub dmPanningWidth: u32,
+    pub dmPanningHeight: u32,
+}
+
+impl Default for DEVMODEW {
+    fn default() -> Self {
+        let mut mode: Self = unsafe { core::mem::zeroed() };
+        mode.dmSize = size_of::<Self>() as u16;
+        mode
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct VideoMode {
+    pub width: u32,
+    pub height: u32,
+    pub refresh_rate: u32,
+    pub bits_per_pixel: u32,
+}
+
+impl VideoMode {
+    pub const fn from_devmode(mode: &DEVMODEW) -> Self {
+        Self {
+            width: mode.dmPelsWidth,
+            height: mode.dmPelsHeight,
+            refresh_rate: mode.dmDisplayFrequency,
+            bits_per_pixel: mode.dmBitsPerPel,
+        }
+    }
+    pub fn devmode(&self) -> DEVMODEW {
+        DEVMODEW {
+            dmFields: DM_PELSWIDTH | DM_PELSHEIGHT | DM_DISPLAYFREQUENCY | DM_BITSPERPEL,
+            dmPelsWidth: self.width,
+            dmPelsHeight: self.height,
+            dmDisplayFrequency: self.refresh_rate,
+            dmBitsPerPel: self.bits_per_pixel,
+            ..Default::default()
+        }
+    }
+}
+
 #[link(name = "user32")]
 extern "system" {
-    pub fn MonitorFromPoint(pt: POINT, dwFlags: u32) -> *mut c_void;
+    pub fn MonitorFromPoint(pt: POINT, dwFlags: u32) -> isize;
+    pub fn MonitorFromWindow(hwnd: isize, dwFlags: u32) -> isize;
+    pub fn MonitorFromRect(lprc: *const RECT, dwFlags: u32) -> isize;
+    pub fn EnumDisplayMonitors(
+        hdc: *mut c_void,
+        lprcClip: *const RECT,
+        lpfnEnum: Option<
+            unsafe extern "system" fn(
+                monitor: isize,
+                hdc: *mut c_void,
+                rect: *mut RECT,
+                lparam: isize,
+            ) -> BOOL,
+        >,
+        dwData: isize,
+    ) -> BOOL;
     /// You must set the cbSize member of the structure to sizeof(MONITORINFO) or sizeof(MONITORINFOEX) before calling the GetMonitorInfo function.
     /// Doing so lets the function determine the type of structure you are passing to it.
-    pub fn GetMonitorInfoA(hMonitor: *mut c_void, lpmi: *mut MONITORINFO) -> BOOL;
+    pub fn GetMonitorInfoA(hMonitor: isize, lpmi: *mut MONITORINFO) -> BOOL;
+    pub fn GetMonitorInfoW(hMonitor: isize, lpmi: *mut MONITORINFOEXW) -> BOOL;
+    pub fn EnumDisplaySettingsW(
+        lpszDeviceName: LPCWSTR,
+        iModeNum: u32,
+        lpDevMode: *mut DEVMODEW,
+    ) -> BOOL;
+    pub fn EnumDisplayDevicesW(
+        lpDevice: LPCWSTR,
+        iDevNum: u32,
+        lpDisplayDevice: *mut DISPLAY_DEVICEW,
+        dwFlags: u32,
+    ) -> BOOL;
+    pub fn ChangeDisplaySettingsExW(
+        lpszDeviceName: LPCWSTR,
+        lpDevMode: *const DEVMODEW,
+        hwnd: isize,
+        dwflags: u32,
+        lParam: *mut c_void,
+    ) -> i32;
+}
+
+pub const MDT_EFFECTIVE_DPI: u32 = 0;
+
+#[link(name = "shcore")]
+extern "system" {
+    pub fn GetDpiForMonitor(
+        hmonitor: isize,
+        dpiType: u32,
+        dpiX: *mut u32,
+        dpiY: *mut u32,
+    ) -> HRESULT;
+}
+
+///Handles to every monitor attached to the desktop.
+pub fn monitor_handles() -> Vec<isize> {
+    unsafe extern "system" fn push(
+        monitor: isize,
+        _hdc: *mut c_void,
+        _rect: *mut RECT,
+        lparam: isize,
+    ) -> BOOL {
+        let handles = &mut *(lparam as *mut Vec<isize>);
+        handles.push(monitor);
+        1
+    }
+
+    let mut handles = Vec::new();
+    unsafe {
+        EnumDisplayMonitors(
+            null_mut(),
+            null(),
+            Some(push),
+            &mut handles as *mut Vec<isize> as isize,
+        )
+    };
+    handles
+}
+
+///`None` if the monitor was removed, handles go stale as soon as it's unplugged.
+pub fn monitor_info(monitor: isize) -> Option<MONITORINFOEXW> {
+    let mut info = MONITORINFOEXW::default();
+    if unsafe { GetMonitorInfoW(monitor, &mut info) } == 0 {
+        return None;
+    }
+    Some(info)
+}
+
+///Falls back to 96 if the DPI can't be read, for example when the monitor was removed.
+pub fn monitor_dpi(monitor: isize) -> u32 {
+    let (mut x, mut y) = (0, 0);
+    let result = unsafe { GetDpiForMonitor(monitor, MDT_EFFECTIVE_DPI, &mut x, &mut y) };
+    if result < 0 || x == 0 {
+        DEFAULT_DPI as u32
+    } else {
+        x
+    }
+}
+
+///Display scale of the monitor, 1.0 is 96 DPI.
+pub fn monitor_scale(monitor: isize) -> f32 {
+    monitor_dpi(monitor) as f32 / DEFAULT_DPI
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Monitor {
+    pub handle: isize,
+    ///Device name such as `\\.\DISPLAY1`, this is what `WindowPlacement` stores.
+    pub name: String,
+    ///Human readable name from the driver, such as `Generic PnP Monitor`.
+    pub description: String,
+    pub rect: IRect,
+    ///The monitor without the taskbar and docked app bars.
+    pub work_area: IRect,
+    pub primary: bool,
+    pub dpi: u32,
+    ///1.0 is 96 DPI.
+    pub scale: f32,
+    ///In hertz, 0 or 1 means the hardware default.
+    pub refresh_rate: u32,
+    pub video_mode: VideoMode,
+}
+
+impl Monitor {
+    ///`None` if the monitor was removed.
+    pub fn from_handle(handle: isize) -> Option<Monitor> {
+        let info = monitor_info(handle)?;
+        let mut current = DEVMODEW::default();
+        let mut device = DISPLAY_DEVICEW::default();
+        unsafe {
+            EnumDisplaySettingsW(info.szDevice.as_ptr(), ENUM_CURRENT_SETTINGS, &mut current);
+            //The first device under an adapter is the monitor attached to it.
+            EnumDisplayDevicesW(info.szDevice.as_ptr(), 0, &mut device, 0);
+        }
+        let dpi = monitor_dpi(handle);
+
+        Some(Monitor {
+            handle,
+            name: from_wide(&info.szDevice),
+            description: from_wide(&device.DeviceString),
+            rect: IRect::from(info.rcMonitor),
+            work_area: IRect::from(info.rcWork),
+            primary: info.dwFlags & MONITORINFOF_PRIMARY != 0,
+            dpi,
+            scale: dpi as f32 / DEFAULT_DPI,
+            refresh_rate: current.dmDisplayFrequency,
+            video_mode: VideoMode::from_devmode(&current),
+        })
+    }
+    ///`None` if the primary monitor was removed while looking it up.
+    pub fn primary() -> Option<Monitor> {
+        Monitor::from_handle(unsafe {
+            MonitorFromPoint(POINT::default(), MONITOR_DEFAULTTOPRIMARY)
+        })
+    }
+    ///Every mode the monitor supports, for `Fullscreen::Exclusive`.
+    pub fn video_modes(&self) -> Vec<VideoMode> {
+        let name = to_wide(&self.name);
+        let mut modes = Vec::new();
+        let mut mode = DEVMODEW::default();
+        let mut i = 0;
+        while unsafe { EnumDisplaySettingsW(name.as_ptr(), i, &mut mode) } != 0 {
+            let video_mode = VideoMode::from_devmode(&mode);
+            //Modes are repeated for each scaling and orientation setting.
+            if !modes.contains(&video_mode) {
+                modes.push(video_mode);
+            }
+            i += 1;
+        }
+        modes
+    }
+}
+
+///Every monitor attached to the desktop, monitors removed while enumerating are skipped.
+pub fn monitors() -> Vec<Monitor> {
+    monitor_handles()
+        .into_iter()
+        .filter_map(Monitor::from_handle)
+        .collect()
+}
+
+///Monitor info for the monitor the window is mostly on, `None` if it was just removed.
+pub fn window_monitor_info(hwnd: isize) -> Option<MONITORINFOEXW> {
+    monitor_info(unsafe { MonitorFromWindow(hwnd, MONITOR_DEFAULTTONEAREST) })
+}
+
+///Work areas of every monitor, without building a full `Monitor` for each.
+pub fn work_areas() -> Vec<IRect> {
+    monitor_handles()
+        .into_iter()
+        .filter_map(monitor_info)
+        .map(|info| IRect::from(info.rcWork))
+        .collect()
 }
@@ -0,0 +1,111 @@
+use crate::*;
+use std::{cell::Cell, sync::atomic::Ordering::Relaxed};
+
+pub const SPI_SETWORKAREA: usize = 0x002F;
+
+///Hidden window that listens for monitors being added, removed or changed.
+///
+///Message-only windows don't receive broadcasts like `WM_DISPLAYCHANGE`,
+///so this is a regular top-level window that is never shown.
+///`EventLoop` turns this into `Event::MonitorsChanged`.
+#[derive(Debug)]
+pub struct MonitorWatcher {
+    pub hwnd: isize,
+    class_name: Vec<u16>,
+    ///Set by `watcher_proc`, several messages arrive for a single change.
+    changed: Box<Cell<bool>>,
+}
+
+unsafe extern "system" fn watcher_proc(
+    hwnd: isize,
+    msg: u32,
+    wparam: usize,
+    lparam: isize,
+) -> isize {
+    let changed = GetWindowLongPtrW(hwnd, GWLP_USERDATA) as *const Cell<bool>;
+    if let Some(changed) = changed.as_ref() {
+        match msg {
+            WM_DISPLAYCHANGE | WM_DPICHANGED => changed.set(true),
+            WM_SETTINGCHANGE if wparam == SPI_SETWORKAREA => changed.set(true),
+            _ => {}
+        }
+    }
+    DefWindowProcW(hwnd, msg, wparam, lparam)
+}
+
+impl MonitorWatcher {
+    pub fn new() -> Self {
+        unsafe {
+            //Otherwise the monitor rects are scaled for a DPI unaware app.
+            SetThreadDpiAwarenessContext(DPI_AWARENESS_CONTEXT_PER_MONITOR_AWARE_V2);
+
+            let class_name = to_wide(&format!(
+                "mini monitor watcher {}",
+                WINDOW_CLASS_COUNT.fetch_add(1, Relaxed)
+            ));
+            let wnd_class = WNDCLASSW {
+                style: 0,
+                wnd_proc: Some(watcher_proc),
+                cls_extra: 0,
+                wnd_extra: 0,
+                instance: 0,
+                icon: 0,
+                cursor: 0,
+                background: 0,
+                menu_name: null(),
+                class_name: class_name.as_ptr(),
+            };
+            RegisterClassW(&wnd_class);
+
+            let hwnd = CreateWindowExW(
+                WS_EX_TOOLWINDOW,
+                class_name.as_ptr(),
+                class_name.as_ptr(),
+                WS_POPUP,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                null(),
+            );
+            assert_ne!(hwnd, 0);
+
+            let changed = Box::new(Cell::new(false));
+            SetWindowLongPtrW(hwnd, GWLP_USERDATA, &*changed as *const Cell<bool> as isize);
+
+            Self {
+                hwnd,
+                class_name,
+                changed,
+            }
+        }
+    }
+    ///The new monitor list if anything changed since the last call.
+    ///Messages must be pumped on this thread for changes to be seen.
+    pub fn changed(&self) -> Option<Vec<Monitor>> {
+        if self.changed.replace(false) {
+            Some(monitors())
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for MonitorWatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for MonitorWatcher {
+    fn drop(&mut self) {
+        unsafe {
+            SetWindowLongPtrW(self.hwnd, GWLP_USERDATA, 0);
+            DestroyWindow(self.hwnd);
+            UnregisterClassW(self.class_name.as_ptr(), 0);
+        }
+    }
+}
@@ -0,0 +1,260 @@
+use crate::*;
+
+///Where a window was, so it can be put back on the next run.
+///
+///The position and size are the restored (not maximized) window rect,
+///the position is relative to the top left of the monitor's work area.
+///
+///The string format is stable:
+///`v1 <x> <y> <width> <height> <maximized> <fullscreen> <monitor>`
+///
+///```
+///# use window::*;
+///let placement: WindowPlacement = r"v1 10 20 800 600 1 0 \\.\DISPLAY1".parse().unwrap();
+///assert!(placement.maximized);
+///assert_eq!(placement.to_string().parse(), Ok(placement));
+///```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct WindowPlacement {
+    ///Device name of the monitor, for example `\\.\DISPLAY1`.
+    pub monitor: String,
+    pub position: LogicalPosition,
+    pub size: LogicalSize,
+    pub maximized: bool,
+    pub fullscreen: bool,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ParsePlacementError {
+    ///Only `v1` exists.
+    UnknownVersion,
+    ///The field is missing or isn't a number.
+    InvalidField(&'static str),
+}
+
+impl core::fmt::Display for ParsePlacementError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParsePlacementError::UnknownVersion => f.write_str("unknown window placement version"),
+            ParsePlacementError::InvalidField(field) => {
+                write!(f, "invalid window placement field: {}", field)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParsePlacementError {}
+
+impl core::fmt::Display for WindowPlacement {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "v1 {} {} {} {} {} {} {}",
+            self.position.x,
+            self.position.y,
+            self.size.width,
+            self.size.height,
+            self.maximized as u8,
+            self.fullscreen as u8,
+            self.monitor
+        )
+    }
+}
+
+impl core::str::FromStr for WindowPlacement {
+    type Err = ParsePlacementError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        //The monitor name goes last, so it can't be split up.
+        let mut fields = s.trim().splitn(8, ' ');
+        if fields.next() != Some("v1") {
+            return Err(ParsePlacementError::UnknownVersion);
+        }
+
+        let mut number = |name: &'static str| -> Result<i32, ParsePlacementError> {
+            fields
+                .next()
+                .and_then(|field| field.parse().ok())
+                .ok_or(ParsePlacementError::InvalidField(name))
+        };
+        let x = number("x")?;
+        let y = number("y")?;
+        let width = number("width")?;
+        let height = number("height")?;
+        let maximized = number("maximized")? != 0;
+        let fullscreen = number("fullscreen")? != 0;
+        let monitor = fields.next().unwrap_or_default().to_string();
+
+        Ok(WindowPlacement {
+            monitor,
+            position: LogicalPosition::new(x, y),
+            size: LogicalSize::new(width, height),
+            maximized,
+            fullscreen,
+        })
+    }
+}
+
+///`rcNormalPosition` is in workspace coordinates, which are offset by the
+///primary monitor's taskbar when it's on the left or top. Tool windows use screen coordinates.
+fn workspace_offset(hwnd: isize) -> (i32, i32) {
+    unsafe {
+        if GetWindowLongPtrW(hwnd, GWL_EXSTYLE) as u32 & WS_EX_TOOLWINDOW != 0 {
+            return (0, 0);
+        }
+        let Some(info) = monitor_info(MonitorFromPoint(POINT::default(), MONITOR_DEFAULTTOPRIMARY))
+        else {
+            return (0, 0);
+        };
+        (
+            info.rcWork.left - info.rcMonitor.left,
+            info.rcWork.top - info.rcMonitor.top,
+        )
+    }
+}
+
+impl Window {
+    ///Save this with `to_string` and pass it to `Window::restore_placement` on the next run.
+    pub fn placement(&self) -> WindowPlacement {
+        unsafe {
+            //Fullscreen windows remember where they came from.
+            let placement = match &self.windowed {
+                Some(windowed) => windowed.placement.clone(),
+                None => {
+                    let mut placement = WINDOWPLACEMENT::default();
+                    GetWindowPlacement(self.hwnd, &mut placement);
+                    placement
+                }
+            };
+
+            let (dx, dy) = workspace_offset(self.hwnd);
+            let mut rect = placement.rcNormalPosition;
+            rect.left += dx;
+            rect.right += dx;
+            rect.top += dy;
+            rect.bottom += dy;
+
+            let monitor = MonitorFromRect(&rect, MONITOR_DEFAULTTONEAREST);
+            //If the monitor was just removed the position is relative to the desktop.
+            let info = monitor_info(monitor).unwrap_or_default();
+            let scale = monitor_scale(monitor);
+            let work = IRect::from(info.rcWork);
+            let rect = IRect::from(rect).offset(-work.x, -work.y).to_logical(scale);
+
+            let maximized = placement.showCmd == SW_SHOWMAXIMIZED as u32
+                || (placement.showCmd == SW_SHOWMINIMIZED as u32
+                    && placement.flags & WPF_RESTORETOMAXIMIZED != 0);
+
+            WindowPlacement {
+                monitor: from_wide(&info.szDevice),
+                position: rect.position(),
+                size: rect.size(),
+                maximized,
+                fullscreen: self.fullscreen.is_some(),
+            }
+        }
+    }
+    ///Puts the window back where it was. If the monitor is gone the primary monitor is used,
+    ///and the window is kept inside of the work area.
+    pub fn restore_placement(&mut self, placement: &WindowPlacement) {
+        unsafe {
+            if self.fullscreen.is_some() {
+                self.set_fullscreen(None);
+            }
+
+            let monitor = monitor_handles()
+                .into_iter()
+                .find(|m| {
+                    monitor_info(*m)
+                        .is_some_and(|info| from_wide(&info.szDevice) == placement.monitor)
+                })
+                .unwrap_or_else(|| MonitorFromPoint(POINT::default(), MONITOR_DEFAULTTOPRIMARY));
+            let Some(info) = monitor_info(monitor) else {
+                return;
+            };
+            let scale = monitor_scale(monitor);
+            let work = IRect::from(info.rcWork);
+
+            let mut rect = IRect::from_position_size(placement.position, placement.size)
+                .to_physical(scale)
+                .offset(work.x, work.y);
+            rect.width = rect.width.max(1);
+            rect.height = rect.height.max(1);
+            let mut rect = RECT::from(rect.clamp(work));
+
+            let (dx, dy) = workspace_offset(self.hwnd);
+            rect.left -= dx;
+            rect.right -= dx;
+            rect.top -= dy;
+            rect.bottom -= dy;
+
+            let mut wp = WINDOWPLACEMENT::default();
+            GetWindowPlacement(self.hwnd, &mut wp);
+            wp.flags = 0;
+            wp.rcNormalPosition = rect;
+            wp.showCmd = if placement.maximized {
+                SW_SHOWMAXIMIZED
+            } else {
+                SW_SHOWNORMAL
+            } as u32;
+            SetWindowPlacement(self.hwnd, &wp);
+
+            //Goes fullscreen on the monitor the window was just moved to.
+            if placement.fullscreen {
+                self.set_fullscreen(Some(Fullscreen::Borderless));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roundtrip(placement: WindowPlacement) {
+        assert_eq!(placement.to_string().parse(), Ok(placement));
+    }
+
+    #[test]
+    fn negative_position() {
+        roundtrip(WindowPlacement {
+            monitor: String::from(r"\\.\DISPLAY2"),
+            position: LogicalPosition::new(-1920, -40),
+            size: LogicalSize::new(800, 600),
+            maximized: false,
+            fullscreen: true,
+        });
+    }
+
+    #[test]
+    fn monitor_names() {
+        roundtrip(WindowPlacement::default());
+        roundtrip(WindowPlacement {
+            monitor: String::from("Generic PnP Monitor 2"),
+            ..Default::default()
+        });
+    }
+
+    #[test]
+    fn errors() {
+        let parse = |s: &str| s.parse::<WindowPlacement>();
+        assert_eq!(parse(""), Err(ParsePlacementError::UnknownVersion));
+        assert_eq!(
+            parse("v2 0 0 1 1 0 0"),
+            Err(ParsePlacementError::UnknownVersion)
+        );
+        assert_eq!(parse("v1"), Err(ParsePlacementError::InvalidField("x")));
+        assert_eq!(
+            parse("v1 0 a 1 1 0 0"),
+            Err(ParsePlacementError::InvalidField("y"))
+        );
+        assert_eq!(
+            parse("v1 0 0 1.5 1 0 0"),
+            Err(ParsePlacementError::InvalidField("width"))
+        );
+        assert_eq!(
+            parse("v1 0 0 1 1 0"),
+            Err(ParsePlacementError::InvalidField("fullscreen"))
+        );
+    }
+}
@@ -0,0 +1,148 @@
+use crate::*;
+
+pub const PT_POINTER: u32 = 1;
+pub const PT_TOUCH: u32 = 2;
+pub const PT_PEN: u32 = 3;
+pub const PT_MOUSE: u32 = 4;
+pub const PT_TOUCHPAD: u32 = 5;
+
+pub const POINTER_FLAG_NONE: u32 = 0x00000000;
+pub const POINTER_FLAG_NEW: u32 = 0x00000001;
+pub const POINTER_FLAG_INRANGE: u32 = 0x00000002;
+pub const POINTER_FLAG_INCONTACT: u32 = 0x00000004;
+pub const POINTER_FLAG_FIRSTBUTTON: u32 = 0x00000010;
+pub const POINTER_FLAG_SECONDBUTTON: u32 = 0x00000020;
+pub const POINTER_FLAG_THIRDBUTTON: u32 = 0x00000040;
+pub const POINTER_FLAG_FOURTHBUTTON: u32 = 0x00000080;
+pub const POINTER_FLAG_FIFTHBUTTON: u32 = 0x00000100;
+pub const POINTER_FLAG_PRIMARY: u32 = 0x00002000;
+pub const POINTER_FLAG_CONFIDENCE: u32 = 0x00004000;
+pub const POINTER_FLAG_CANCELED: u32 = 0x00008000;
+pub const POINTER_FLAG_DOWN: u32 = 0x00010000;
+pub const POINTER_FLAG_UPDATE: u32 = 0x00020000;
+pub const POINTER_FLAG_UP: u32 = 0x00040000;
+pub const POINTER_FLAG_WHEEL: u32 = 0x00080000;
+pub const POINTER_FLAG_HWHEEL: u32 = 0x00100000;
+pub const POINTER_FLAG_CAPTURECHANGED: u32 = 0x00200000;
+pub const POINTER_FLAG_HASTRANSFORM: u32 = 0x00400000;
+
+pub const PEN_FLAG_NONE: u32 = 0x00000000;
+pub const PEN_FLAG_BARREL: u32 = 0x00000001;
+pub const PEN_FLAG_INVERTED: u32 = 0x00000002;
+pub const PEN_FLAG_ERASER: u32 = 0x00000004;
+
+///Pen pressure is normalized to a range of 0 to 1024.
+pub const MAX_PEN_PRESSURE: f32 = 1024.0;
+
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct POINTER_INFO {
+    pub pointerType: u32,
+    pub pointerId: u32,
+    pub frameId: u32,
+    pub pointerFlags: u32,
+    pub sourceDevice: HANDLE,
+    pub hwndTarget: HWND,
+    pub ptPixelLocation: POINT,
+    pub ptHimetricLocation: POINT,
+    pub ptPixelLocationRaw: POINT,
+    pub ptHimetricLocationRaw: POINT,
+    pub dwTime: DWORD,
+    pub historyCount: u32,
+    pub InputData: i32,
+    pub dwKeyStates: DWORD,
+    pub PerformanceCount: u64,
+    pub ButtonChangeType: i32,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct POINTER_PEN_INFO {
+    pub pointerInfo: POINTER_INFO,
+    pub penFlags: u32,
+    pub penMask: u32,
+    pub pressure: u32,
+    pub rotation: u32,
+    pub tiltX: i32,
+    pub tiltY: i32,
+}
+
+#[link(name = "user32")]
+extern "system" {
+    pub fn GetPointerType(pointerId: u32, pointerType: *mut u32) -> BOOL;
+    pub fn GetPointerInfo(pointerId: u32, pointerInfo: *mut POINTER_INFO) -> BOOL;
+    pub fn GetPointerPenInfo(pointerId: u32, penInfo: *mut POINTER_PEN_INFO) -> BOOL;
+    pub fn ScreenToClient(hwnd: isize, lpPoint: *mut POINT) -> BOOL;
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum TouchPhase {
+    Started,
+    Moved,
+    Ended,
+    Cancelled,
+}
+
+impl TouchPhase {
+    pub const fn from_flags(flags: u32) -> Self {
+        if flags & POINTER_FLAG_CANCELED != 0 {
+            TouchPhase::Cancelled
+        } else if flags & POINTER_FLAG_DOWN != 0 {
+            TouchPhase::Started
+        } else if flags & POINTER_FLAG_UP != 0 {
+            TouchPhase::Ended
+        } else {
+            TouchPhase::Moved
+        }
+    }
+}
+
+///Converts `WM_POINTERDOWN`, `WM_POINTERUPDATE` and `WM_POINTERUP` into touch and pen events.
+///Mouse pointers are ignored since they already have their own messages.
+pub(crate) unsafe fn pointer_event(hwnd: isize, wparam: usize) -> Option<Event> {
+    let id = wparam.low() as u32;
+
+    let mut pointer_type = 0;
+    if GetPointerType(id, &mut pointer_type) == 0 {
+        return None;
+    }
+
+    match pointer_type {
+        PT_TOUCH => {
+            let mut info: POINTER_INFO = core::mem::zeroed();
+            if GetPointerInfo(id, &mut info) == 0 {
+                return None;
+            }
+
+            //Pointer locations are in screen coordinates.
+            let mut point = info.ptPixelLocation.clone();
+            ScreenToClient(hwnd, &mut point);
+
+            Some(Event::Touch {
+                id,
+                phase: TouchPhase::from_flags(info.pointerFlags),
+                x: point.x,
+                y: point.y,
+            })
+        }
+        PT_PEN => {
+            let mut info: POINTER_PEN_INFO = core::mem::zeroed();
+            if GetPointerPenInfo(id, &mut info) == 0 {
+                return None;
+            }
+
+            let mut point = info.pointerInfo.ptPixelLocation.clone();
+            ScreenToClient(hwnd, &mut point);
+
+            Some(Event::Pen {
+                x: point.x,
+                y: point.y,
+                pressure: info.pressure as f32 / MAX_PEN_PRESSURE,
+                tilt: (info.tiltX, info.tiltY),
+                buttons: info.penFlags,
+                eraser: info.penFlags & (PEN_FLAG_ERASER | PEN_FLAG_INVERTED) != 0,
+            })
+        }
+        _ => None,
+    }
+}
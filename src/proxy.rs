@@ -0,0 +1,124 @@
+use crate::*;
+use std::{
+    any::Any,
+    collections::VecDeque,
+    sync::{
+        atomic::{AtomicU32, Ordering::Relaxed},
+        Arc, Mutex,
+    },
+};
+
+#[link(name = "user32")]
+extern "system" {
+    pub fn PostMessageW(hwnd: isize, msg: u32, wparam: usize, lparam: isize) -> BOOL;
+    pub fn RegisterWindowMessageW(string: *const u16) -> u32;
+}
+
+static WAKE_MESSAGE: AtomicU32 = AtomicU32::new(0);
+
+///Posted by `EventProxy` to wake up the window's message loop, it carries no data.
+///Registered so it can't collide with `WM_APP` or `WM_USER` messages from anyone else.
+///`wnd_proc` passes it to `DefWindowProcW`, the events are in the window's `ProxyQueue`.
+pub fn wake_message() -> u32 {
+    let msg = WAKE_MESSAGE.load(Relaxed);
+    if msg != 0 {
+        return msg;
+    }
+    let msg = unsafe { RegisterWindowMessageW(to_wide("mini window wake").as_ptr()) };
+    assert_ne!(msg, 0);
+    WAKE_MESSAGE.store(msg, Relaxed);
+    msg
+}
+
+//Events are queued from other threads, keep `Event` free of raw pointers.
+const _: () = {
+    const fn send<T: Send>() {}
+    send::<Event>()
+};
+
+///Events sent from other threads, drained by `Window::event`.
+#[derive(Debug, Default)]
+pub(crate) struct ProxyQueue {
+    pub events: VecDeque<Event>,
+    ///Set once the window is destroyed, sending fails after this.
+    pub closed: bool,
+}
+
+///Payload sent through an `EventProxy`.
+pub struct UserEvent(pub Box<dyn Any + Send>);
+
+impl UserEvent {
+    pub fn is<T: Any>(&self) -> bool {
+        self.0.is::<T>()
+    }
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        self.0.downcast_ref()
+    }
+    ///Gives the event back if it's a different type.
+    pub fn downcast<T: Any>(self) -> Result<T, UserEvent> {
+        self.0.downcast().map(|t| *t).map_err(UserEvent)
+    }
+}
+
+impl core::fmt::Debug for UserEvent {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("UserEvent(..)")
+    }
+}
+
+///Payloads can't be compared, only the same allocation is equal.
+impl PartialEq for UserEvent {
+    fn eq(&self, other: &Self) -> bool {
+        core::ptr::addr_eq(&*self.0, &*other.0)
+    }
+}
+
+///Wakes up a window's event loop from any thread.
+///
+///```no_run
+///# use window::*;
+///let window = create_window("Window", None, LogicalSize::new(600, 400), WindowStyle::DEFAULT);
+///let proxy = window.proxy();
+///std::thread::spawn(move || proxy.send(String::from("Done")));
+///
+///if let Some(Event::User(event)) = window.event_blocking() {
+///    println!("{}", event.downcast::<String>().unwrap());
+///}
+///```
+#[derive(Debug, Clone)]
+pub struct EventProxy {
+    pub hwnd: isize,
+    pub(crate) queue: Arc<Mutex<ProxyQueue>>,
+}
+
+impl EventProxy {
+    ///Comes out of `Window::event` as `Event::User`.
+    ///Returns the payload if the window has been destroyed.
+    pub fn send<T: Any + Send>(&self, payload: T) -> Result<(), T> {
+        let mut queue = self.queue.lock().unwrap();
+        if queue.closed {
+            return Err(payload);
+        }
+        queue
+            .events
+            .push_back(Event::User(UserEvent(Box::new(payload))));
+        drop(queue);
+
+        //If the window is destroyed before this, the queue frees the payload.
+        unsafe { PostMessageW(self.hwnd, wake_message(), 0, 0) };
+        Ok(())
+    }
+    ///Comes out of `Window::event` as `Event::Wake`, use it to request a redraw.
+    ///Returns false if the window has been destroyed.
+    pub fn wake(&self) -> bool {
+        let mut queue = self.queue.lock().unwrap();
+        if queue.closed {
+            return false;
+        }
+        queue.events.push_back(Event::Wake);
+        drop(queue);
+
+        unsafe { PostMessageW(self.hwnd, wake_message(), 0, 0) };
+        true
+    }
+}
@@ -0,0 +1,287 @@
+use crate::*;
+
+///How the window looks and behaves, independent of the platform.
+///Use `WindowStyle::win32` to get the raw `WS_*` and `WS_EX_*` flags.
+///
+///```
+///# use window::*;
+///let style = WindowStyle::DEFAULT.resizable(false).topmost(true);
+///```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct WindowStyle {
+    ///Title bar and borders.
+    pub decorations: bool,
+    pub resizable: bool,
+    pub minimize_button: bool,
+    pub maximize_button: bool,
+    pub visible: bool,
+    ///Stays above all non-topmost windows.
+    pub topmost: bool,
+    ///Smaller title bar and hidden from alt-tab.
+    pub tool_window: bool,
+    ///Show a button in the taskbar.
+    ///Windows only hides the button from tool windows, so turning this off
+    ///also gives the window a smaller title bar and hides it from alt-tab.
+    pub taskbar: bool,
+    ///Mouse input goes to whatever is underneath. Requires `transparent`.
+    pub click_through: bool,
+    ///Per-pixel transparency, the buffer is drawn as `0xAARRGGBB`.
+    ///Transparent pixels don't receive mouse input. See `Window::draw`.
+    pub transparent: bool,
+    ///Raw `WS_*` flags combined with the options above.
+    style: u32,
+    ///Raw `WS_EX_*` flags combined with the options above.
+    exstyle: u32,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum StyleError {
+    ///`WS_EX_TRANSPARENT` only lets clicks through layered windows.
+    ClickThroughNeedsTransparent,
+    ///There's no title bar to put the buttons on.
+    ButtonsNeedDecorations,
+    ///Windows can't have a taskbar button without being a top-level window.
+    TaskbarNeedsTopLevel,
+    ///`UpdateLayeredWindow` replaces the title bar and borders with the buffer.
+    TransparentNeedsNoDecorations,
+}
+
+impl core::fmt::Display for StyleError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            StyleError::ClickThroughNeedsTransparent => {
+                f.write_str("click through windows must also be transparent")
+            }
+            StyleError::ButtonsNeedDecorations => {
+                f.write_str("minimize and maximize buttons require decorations")
+            }
+            StyleError::TaskbarNeedsTopLevel => {
+                f.write_str("child windows cannot have a taskbar button")
+            }
+            StyleError::TransparentNeedsNoDecorations => {
+                f.write_str("transparent windows cannot have decorations")
+            }
+        }
+    }
+}
+
+impl std::error::Error for StyleError {}
+
+impl WindowStyle {
+    pub const DEFAULT: Self = Self {
+        decorations: true,
+        resizable: true,
+        minimize_button: true,
+        maximize_button: true,
+        visible: true,
+        topmost: false,
+        tool_window: false,
+        taskbar: true,
+        click_through: false,
+        transparent: false,
+        style: 0,
+        exstyle: 0,
+    };
+
+    pub const BORDERLESS: Self = Self {
+        decorations: false,
+        resizable: false,
+        minimize_button: false,
+        maximize_button: false,
+        ..Self::DEFAULT
+    };
+
+    ///A see-through HUD that stays on top and ignores the mouse.
+    pub const OVERLAY: Self = Self {
+        topmost: true,
+        taskbar: false,
+        transparent: true,
+        click_through: true,
+        ..Self::BORDERLESS
+    };
+
+    ///The minimize and maximize buttons are removed along with the title bar.
+    pub const fn decorations(mut self, decorations: bool) -> Self {
+        self.decorations = decorations;
+        if !decorations {
+            self.minimize_button = false;
+            self.maximize_button = false;
+        }
+        self
+    }
+    pub const fn resizable(mut self, resizable: bool) -> Self {
+        self.resizable = resizable;
+        self
+    }
+    pub const fn minimize_button(mut self, minimize_button: bool) -> Self {
+        self.minimize_button = minimize_button;
+        self
+    }
+    pub const fn maximize_button(mut self, maximize_button: bool) -> Self {
+        self.maximize_button = maximize_button;
+        self
+    }
+    pub const fn visible(mut self, visible: bool) -> Self {
+        self.visible = visible;
+        self
+    }
+    pub const fn topmost(mut self, topmost: bool) -> Self {
+        self.topmost = topmost;
+        self
+    }
+    pub const fn tool_window(mut self, tool_window: bool) -> Self {
+        self.tool_window = tool_window;
+        self
+    }
+    ///Hiding the taskbar button turns the window into a tool window, see `WindowStyle::taskbar`.
+    pub const fn taskbar(mut self, taskbar: bool) -> Self {
+        self.taskbar = taskbar;
+        self
+    }
+    pub const fn click_through(mut self, click_through: bool) -> Self {
+        self.click_through = click_through;
+        self
+    }
+    pub const fn transparent(mut self, transparent: bool) -> Self {
+        self.transparent = transparent;
+        self
+    }
+    pub(crate) const fn ex_style(mut self, flags: u32) -> Self {
+        self.exstyle |= flags;
+        self
+    }
+    pub(crate) const fn style(mut self, flags: u32) -> Self {
+        self.style |= flags;
+        self
+    }
+    ///Keeps the raw flags from `other`, which only come from the kind of window.
+    ///Child windows can't have a taskbar button, so it's turned off for them.
+    pub(crate) const fn raw_flags_from(mut self, other: WindowStyle) -> Self {
+        self.style |= other.style;
+        self.exstyle |= other.exstyle;
+        if self.style & WS_CHILD != 0 {
+            self.taskbar = false;
+        }
+        self
+    }
+    ///Checks for options that don't work together.
+    pub const fn validate(&self) -> Result<(), StyleError> {
+        if self.click_through && !self.transparent {
+            return Err(StyleError::ClickThroughNeedsTransparent);
+        }
+        if (self.minimize_button || self.maximize_button) && !self.decorations {
+            return Err(StyleError::ButtonsNeedDecorations);
+        }
+        if self.transparent && self.decorations {
+            return Err(StyleError::TransparentNeedsNoDecorations);
+        }
+        if self.taskbar && self.style & WS_CHILD != 0 {
+            return Err(StyleError::TaskbarNeedsTopLevel);
+        }
+        Ok(())
+    }
+    ///Returns `(style, exstyle)`.
+    pub const fn win32(&self) -> (u32, u32) {
+        let mut style = if self.decorations {
+            WS_OVERLAPPED | WS_CAPTION | WS_SYSMENU
+        } else if self.style & WS_CHILD != 0 {
+            0
+        } else {
+            WS_POPUP
+        };
+        let mut exstyle = 0;
+
+        if self.resizable {
+            style |= WS_THICKFRAME;
+        }
+        if self.minimize_button {
+            style |= WS_MINIMIZEBOX;
+        }
+        if self.maximize_button {
+            style |= WS_MAXIMIZEBOX;
+        }
+        if self.visible {
+            style |= WS_VISIBLE;
+        }
+        if self.topmost {
+            exstyle |= WS_EX_TOPMOST;
+        }
+
+        //Tool windows don't get a taskbar button unless forced to.
+        //Regular windows can only be hidden from the taskbar by becoming a tool window.
+        match (self.tool_window, self.taskbar) {
+            (true, true) => exstyle |= WS_EX_TOOLWINDOW | WS_EX_APPWINDOW,
+            (true, false) | (false, false) => exstyle |= WS_EX_TOOLWINDOW,
+            (false, true) => {}
+        }
+
+        if self.transparent {
+            exstyle |= WS_EX_LAYERED;
+        }
+        if self.click_through {
+            exstyle |= WS_EX_TRANSPARENT;
+        }
+
+        (style | self.style, exstyle | self.exstyle)
+    }
+}
+
+impl Default for WindowStyle {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decorations_clear_buttons() {
+        let style = WindowStyle::DEFAULT.decorations(false);
+        assert_eq!(style.validate(), Ok(()));
+        assert_eq!(style.win32().0 & (WS_MINIMIZEBOX | WS_MAXIMIZEBOX), 0);
+
+        //Asking for the buttons afterwards is still an error.
+        let style = style.minimize_button(true);
+        assert_eq!(style.validate(), Err(StyleError::ButtonsNeedDecorations));
+    }
+
+    #[test]
+    fn presets_are_valid() {
+        for style in [
+            WindowStyle::DEFAULT,
+            WindowStyle::BORDERLESS,
+            WindowStyle::OVERLAY,
+        ] {
+            assert_eq!(style.validate(), Ok(()), "{style:?}");
+        }
+    }
+
+    #[test]
+    fn hidden_from_taskbar() {
+        let (_, exstyle) = WindowStyle::DEFAULT.taskbar(false).win32();
+        assert_ne!(exstyle & WS_EX_TOOLWINDOW, 0);
+        assert_eq!(exstyle & WS_EX_APPWINDOW, 0);
+
+        let (_, exstyle) = WindowStyle::DEFAULT.tool_window(true).win32();
+        assert_ne!(exstyle & WS_EX_APPWINDOW, 0);
+    }
+
+    #[test]
+    fn raw_flags_are_kept() {
+        let child = WindowStyle::BORDERLESS.style(WS_CHILD | WS_CLIPSIBLINGS);
+        let tooltip = WindowStyle::BORDERLESS.ex_style(WS_EX_NOACTIVATE);
+
+        let style = WindowStyle::DEFAULT.raw_flags_from(child);
+        assert_eq!(style.validate(), Ok(()));
+        let (style, _) = style.win32();
+        assert_eq!(
+            style & (WS_CHILD | WS_CLIPSIBLINGS),
+            WS_CHILD | WS_CLIPSIBLINGS
+        );
+
+        let (_, exstyle) = WindowStyle::DEFAULT.raw_flags_from(tooltip).win32();
+        assert_ne!(exstyle & WS_EX_NOACTIVATE, 0);
+    }
+}
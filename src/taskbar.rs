@@ -0,0 +1,230 @@
+use crate::*;
+
+pub const CLSCTX_INPROC_SERVER: u32 = 0x1;
+pub const COINIT_APARTMENTTHREADED: u32 = 0x2;
+
+pub const CLSID_TASKBARLIST: GUID = GUID::from_u128(0x56fdf344_fd6d_11d0_958a_006097c9a090);
+pub const IID_ITASKBARLIST3: GUID = GUID::from_u128(0xea1afb91_9e28_4b86_90e9_9e9f8a5eefaf);
+
+pub const TBPF_NOPROGRESS: u32 = 0x0;
+pub const TBPF_INDETERMINATE: u32 = 0x1;
+pub const TBPF_NORMAL: u32 = 0x2;
+pub const TBPF_ERROR: u32 = 0x4;
+pub const TBPF_PAUSED: u32 = 0x8;
+
+pub const ICON_SMALL: usize = 0;
+pub const ICON_BIG: usize = 1;
+
+#[link(name = "ole32")]
+extern "system" {
+    pub fn CoInitializeEx(pvReserved: *mut c_void, dwCoInit: u32) -> HRESULT;
+    pub fn CoCreateInstance(
+        rclsid: *const GUID,
+        pUnkOuter: *mut c_void,
+        dwClsContext: u32,
+        riid: *const GUID,
+        ppv: *mut *mut c_void,
+    ) -> HRESULT;
+}
+
+#[link(name = "user32")]
+extern "system" {
+    pub fn CreateIconIndirect(piconinfo: *const ICONINFO) -> *mut c_void;
+    pub fn DestroyIcon(hIcon: *mut c_void) -> BOOL;
+    pub fn SendMessageW(hwnd: isize, msg: u32, wparam: usize, lparam: isize) -> isize;
+    pub fn SetWindowTextW(hwnd: isize, lpString: LPCWSTR) -> BOOL;
+}
+
+#[link(name = "Gdi32")]
+extern "system" {
+    pub fn CreateBitmap(
+        nWidth: i32,
+        nHeight: i32,
+        nPlanes: u32,
+        nBitCount: u32,
+        lpBits: *const c_void,
+    ) -> *mut c_void;
+}
+
+#[repr(C)]
+#[derive(Debug)]
+pub struct ICONINFO {
+    pub fIcon: BOOL,
+    pub xHotspot: u32,
+    pub yHotspot: u32,
+    pub hbmMask: *mut c_void,
+    pub hbmColor: *mut c_void,
+}
+
+///An icon for the title bar, taskbar or taskbar overlay.
+#[derive(Debug)]
+pub struct Icon {
+    pub handle: *mut c_void,
+}
+
+impl Icon {
+    ///`rgba` is 4 bytes per pixel, row by row from the top left.
+    pub fn from_rgba(rgba: &[u8], width: u32, height: u32) -> Icon {
+        assert_eq!(rgba.len(), width as usize * height as usize * 4);
+
+        unsafe {
+            //Color bitmaps are BGRA.
+            let mut color = LayeredSurface::new(width as i32, height as i32);
+            for (dst, src) in color.pixels().iter_mut().zip(rgba.chunks_exact(4)) {
+                *dst = u32::from_be_bytes([src[3], src[0], src[1], src[2]]);
+            }
+
+            //The mask is ignored when the color bitmap has alpha, but it's still required.
+            let mask = CreateBitmap(width as i32, height as i32, 1, 1, null());
+            assert!(!mask.is_null());
+
+            let info = ICONINFO {
+                fIcon: 1,
+                xHotspot: 0,
+                yHotspot: 0,
+                hbmMask: mask,
+                hbmColor: color.bitmap,
+            };
+
+            //The bitmaps are copied, so they can be deleted right away.
+            let handle = CreateIconIndirect(&info);
+            DeleteObject(mask);
+            assert!(!handle.is_null());
+
+            Icon { handle }
+        }
+    }
+}
+
+impl Drop for Icon {
+    fn drop(&mut self) {
+        unsafe { DestroyIcon(self.handle) };
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum TaskbarProgress {
+    None,
+    ///Pulses without showing how much is done.
+    Indeterminate,
+    ///Progress from 0.0 to 1.0.
+    Normal(f32),
+    ///Shown in red.
+    Error(f32),
+    ///Shown in yellow.
+    Paused(f32),
+}
+
+#[repr(C)]
+struct ITaskbarList3Vtbl {
+    QueryInterface: usize,
+    AddRef: usize,
+    Release: unsafe extern "system" fn(this: *mut ITaskbarList3) -> u32,
+    HrInit: unsafe extern "system" fn(this: *mut ITaskbarList3) -> HRESULT,
+    AddTab: usize,
+    DeleteTab: usize,
+    ActivateTab: usize,
+    SetActiveAlt: usize,
+    MarkFullscreenWindow: usize,
+    SetProgressValue: unsafe extern "system" fn(
+        this: *mut ITaskbarList3,
+        hwnd: isize,
+        completed: u64,
+        total: u64,
+    ) -> HRESULT,
+    SetProgressState:
+        unsafe extern "system" fn(this: *mut ITaskbarList3, hwnd: isize, flags: u32) -> HRESULT,
+    RegisterTab: usize,
+    UnregisterTab: usize,
+    SetTabOrder: usize,
+    SetTabActive: usize,
+    ThumbBarAddButtons: usize,
+    ThumbBarUpdateButtons: usize,
+    ThumbBarSetImageList: usize,
+    SetOverlayIcon: unsafe extern "system" fn(
+        this: *mut ITaskbarList3,
+        hwnd: isize,
+        icon: *mut c_void,
+        description: LPCWSTR,
+    ) -> HRESULT,
+}
+
+#[repr(C)]
+pub struct ITaskbarList3 {
+    vtable: *const ITaskbarList3Vtbl,
+}
+
+///Owned reference to the shell's `ITaskbarList3`.
+#[derive(Debug)]
+pub struct Taskbar {
+    list: *mut ITaskbarList3,
+}
+
+impl Taskbar {
+    ///Returns `None` if the shell isn't running or COM can't be initialized.
+    pub fn new() -> Option<Taskbar> {
+        unsafe {
+            //S_FALSE means COM was already initialized, which is fine.
+            if CoInitializeEx(null_mut(), COINIT_APARTMENTTHREADED) < 0 {
+                return None;
+            }
+
+            let mut list: *mut c_void = null_mut();
+            let result = CoCreateInstance(
+                &CLSID_TASKBARLIST,
+                null_mut(),
+                CLSCTX_INPROC_SERVER,
+                &IID_ITASKBARLIST3,
+                &mut list,
+            );
+            if result < 0 || list.is_null() {
+                return None;
+            }
+
+            let taskbar = Taskbar {
+                list: list as *mut ITaskbarList3,
+            };
+            if (taskbar.vtable().HrInit)(taskbar.list) < 0 {
+                return None;
+            }
+            Some(taskbar)
+        }
+    }
+    unsafe fn vtable(&self) -> &ITaskbarList3Vtbl {
+        &*(*self.list).vtable
+    }
+    pub fn set_progress(&self, hwnd: isize, progress: TaskbarProgress) {
+        //Progress values are integers, this is plenty of precision.
+        const TOTAL: u64 = 10_000;
+
+        let (state, value) = match progress {
+            TaskbarProgress::None => (TBPF_NOPROGRESS, None),
+            TaskbarProgress::Indeterminate => (TBPF_INDETERMINATE, None),
+            TaskbarProgress::Normal(value) => (TBPF_NORMAL, Some(value)),
+            TaskbarProgress::Error(value) => (TBPF_ERROR, Some(value)),
+            TaskbarProgress::Paused(value) => (TBPF_PAUSED, Some(value)),
+        };
+
+        unsafe {
+            let vtable = self.vtable();
+            //Setting the value changes the state to normal, so set the state after.
+            if let Some(value) = value {
+                let completed = (value.clamp(0.0, 1.0) * TOTAL as f32) as u64;
+                (vtable.SetProgressValue)(self.list, hwnd, completed, TOTAL);
+            }
+            (vtable.SetProgressState)(self.list, hwnd, state);
+        }
+    }
+    ///The taskbar makes it's own copy of the icon.
+    pub fn set_overlay(&self, hwnd: isize, icon: Option<&Icon>, description: &str) {
+        let description = to_wide(description);
+        let icon = icon.map(|icon| icon.handle).unwrap_or(null_mut());
+        unsafe { (self.vtable().SetOverlayIcon)(self.list, hwnd, icon, description.as_ptr()) };
+    }
+}
+
+impl Drop for Taskbar {
+    fn drop(&mut self) {
+        unsafe { (self.vtable().Release)(self.list) };
+    }
+}
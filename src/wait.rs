@@ -0,0 +1,56 @@
+use crate::*;
+use std::time::{Duration, Instant};
+
+pub const INFINITE: u32 = 0xFFFFFFFF;
+pub const WAIT_TIMEOUT: u32 = 0x102;
+pub const WAIT_FAILED: u32 = 0xFFFFFFFF;
+
+pub const QS_ALLINPUT: u32 = 0x04FF;
+///Also wake for messages that were seen by `PeekMessage` but not removed.
+pub const MWMO_INPUTAVAILABLE: u32 = 0x0004;
+
+#[link(name = "user32")]
+extern "system" {
+    pub fn MsgWaitForMultipleObjectsEx(
+        nCount: u32,
+        pHandles: *const HANDLE,
+        dwMilliseconds: u32,
+        dwWakeMask: u32,
+        dwFlags: u32,
+    ) -> u32;
+}
+
+///How `EventLoop::next_event` waits when there are no events.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum ControlFlow {
+    ///Return straight away, for games and animations.
+    #[default]
+    Poll,
+    ///Sleep until there is an event.
+    Wait,
+    ///Sleep until there is an event or the deadline has passed.
+    WaitUntil(Instant),
+}
+
+///Sleeps until a message arrives or the timeout ends.
+///Returns false if it timed out.
+///
+///Messages that are already queued end the wait straight away,
+///so everything in the thread's queue must be removed before calling this again.
+pub fn wait_for_message(timeout: Duration) -> bool {
+    //Round up, waking early would spin until the deadline.
+    let ms = timeout
+        .as_nanos()
+        .div_ceil(1_000_000)
+        .min(INFINITE as u128 - 1) as u32;
+    let result =
+        unsafe { MsgWaitForMultipleObjectsEx(0, null(), ms, QS_ALLINPUT, MWMO_INPUTAVAILABLE) };
+    if result == WAIT_FAILED {
+        let last_error = unsafe { GetLastError() };
+        panic!(
+            "Error with `MsgWaitForMultipleObjectsEx`, error code: {}",
+            last_error
+        );
+    }
+    result != WAIT_TIMEOUT
+}
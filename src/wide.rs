@@ -0,0 +1,71 @@
+//! UTF-8 <-> UTF-16 conversion for the wide (W) Win32 functions.
+
+///Converts to a null terminated UTF-16 string.
+///Anything after an interior null will be ignored by Windows.
+pub fn to_wide(s: &str) -> Vec<u16> {
+    s.encode_utf16().chain(Some(0)).collect()
+}
+
+///Reads up to the first null, or the whole slice if there isn't one.
+///Unpaired surrogates are replaced with `U+FFFD`.
+pub fn from_wide(wide: &[u16]) -> String {
+    let len = wide.iter().position(|c| *c == 0).unwrap_or(wide.len());
+    String::from_utf16_lossy(&wide[..len])
+}
+
+///Reads a null terminated UTF-16 string from a pointer.
+///
+///# Safety
+///
+///`ptr` must be null or point to readable memory that ends with a null.
+pub unsafe fn from_wide_ptr(ptr: *const u16) -> String {
+    if ptr.is_null() {
+        return String::new();
+    }
+    let mut len = 0;
+    while *ptr.add(len) != 0 {
+        len += 1;
+    }
+    from_wide(core::slice::from_raw_parts(ptr, len))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        assert_eq!(to_wide(""), [0]);
+        assert_eq!(from_wide(&[]), "");
+        assert_eq!(from_wide(&[0]), "");
+        assert_eq!(unsafe { from_wide_ptr(core::ptr::null()) }, "");
+        assert_eq!(unsafe { from_wide_ptr([0].as_ptr()) }, "");
+    }
+
+    #[test]
+    fn nul() {
+        assert_eq!(to_wide("ab"), [b'a' as u16, b'b' as u16, 0]);
+        //Interior nulls are kept, Windows will stop reading there.
+        assert_eq!(to_wide("a\0b"), [b'a' as u16, 0, b'b' as u16, 0]);
+
+        assert_eq!(from_wide(&to_wide("a\0b")), "a");
+        assert_eq!(from_wide(&[b'a' as u16, 0, 0, 0]), "a");
+        //No terminator at all.
+        assert_eq!(from_wide(&[b'a' as u16, b'b' as u16]), "ab");
+
+        let wide = to_wide("title\0ignored");
+        assert_eq!(unsafe { from_wide_ptr(wide.as_ptr()) }, "title");
+    }
+
+    #[test]
+    fn surrogate_pairs() {
+        let wide = to_wide("🙂 ü");
+        assert_eq!(wide, [0xD83D, 0xDE42, b' ' as u16, 0xFC, 0]);
+        assert_eq!(from_wide(&wide), "🙂 ü");
+        assert_eq!(unsafe { from_wide_ptr(wide.as_ptr()) }, "🙂 ü");
+
+        //Unpaired surrogates from a broken file name.
+        assert_eq!(from_wide(&[0xD83D, b'a' as u16, 0]), "\u{FFFD}a");
+        assert_eq!(from_wide(&[0xDE42]), "\u{FFFD}");
+    }
+}
@@ -1,23 +1,178 @@
 use crate::*;
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    sync::{
+        atomic::{AtomicUsize, Ordering::*},
+        Arc, Mutex,
+    },
+};
+
 pub const DEFAULT_DPI: f32 = 96.0;
 
-pub fn create_window(
+///Timer used to keep calling the frame callback while moving or resizing.
+pub const LIVE_RESIZE_TIMER: usize = 1;
+///Roughly 60 frames per second, timers aren't very precise anyway.
+pub const LIVE_RESIZE_INTERVAL_MS: u32 = 16;
+///Logical size of the strip at the top of a window that `keep_on_screen` keeps on a monitor.
+pub const MIN_VISIBLE: i32 = 32;
+
+///Every window class from `create` starts with this, followed by a number.
+const CLASS_PREFIX: &str = "mini window ";
+
+pub(crate) static WINDOW_CLASS_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+///`None` lets Windows pick the position. Logical sizes are scaled for the monitor the window opens on.
+///
+///```no_run
+///# use window::*;
+///let window = create_window("Window", None, LogicalSize::new(600, 400), WindowStyle::DEFAULT);
+///```
+pub fn create_window<U: Unit>(
+    title: &str,
+    position: Option<PhysicalPosition>,
+    size: Size<U>,
+    style: WindowStyle,
+) -> std::pin::Pin<Box<Window>> {
+    create(title, position, size, style, WindowKind::TopLevel, 0)
+}
+
+///Clipped to the parent's client area, `position` is relative to it.
+pub fn create_child_window<U: Unit>(
+    parent: &Window,
+    position: PhysicalPosition,
+    size: Size<U>,
+    style: WindowStyle,
+) -> std::pin::Pin<Box<Window>> {
+    let style = style.taskbar(false).style(WS_CHILD | WS_CLIPSIBLINGS);
+    create(
+        "",
+        Some(position),
+        size,
+        style,
+        WindowKind::Child,
+        parent.hwnd,
+    )
+}
+
+///Always above the owner, and hidden or destroyed along with it.
+pub fn create_owned_window<U: Unit>(
+    owner: &Window,
+    title: &str,
+    position: Option<PhysicalPosition>,
+    size: Size<U>,
+    style: WindowStyle,
+) -> std::pin::Pin<Box<Window>> {
+    create(title, position, size, style, WindowKind::Owned, owner.hwnd)
+}
+
+///A dropdown or context menu in screen coordinates, it can extend past the owner.
+///Closes when it loses focus, which includes clicking anywhere outside of it.
+pub fn create_popup<U: Unit>(
+    owner: &Window,
+    position: PhysicalPosition,
+    size: Size<U>,
+) -> std::pin::Pin<Box<Window>> {
+    let style = WindowStyle::BORDERLESS.taskbar(false);
+    create(
+        "",
+        Some(position),
+        size,
+        style,
+        WindowKind::Popup,
+        owner.hwnd,
+    )
+}
+
+///Never takes focus from the owner, closes when the owner is clicked or deactivated.
+pub fn create_tooltip<U: Unit>(
+    owner: &Window,
+    position: PhysicalPosition,
+    size: Size<U>,
+) -> std::pin::Pin<Box<Window>> {
+    let style = WindowStyle::BORDERLESS
+        .taskbar(false)
+        .visible(false)
+        .ex_style(WS_EX_NOACTIVATE);
+    let mut window = create(
+        "",
+        Some(position),
+        size,
+        style,
+        WindowKind::Tooltip,
+        owner.hwnd,
+    );
+    unsafe { ShowWindow(window.hwnd, SW_SHOWNOACTIVATE) };
+    //Otherwise `set_style` would hide it again.
+    window.style.visible = true;
+    window
+}
+
+///Disables the owner until the modal window is closed.
+pub fn create_modal_window<U: Unit>(
+    owner: &Window,
     title: &str,
-    x: i32,
-    y: i32,
-    width: i32,
-    height: i32,
+    position: Option<PhysicalPosition>,
+    size: Size<U>,
     style: WindowStyle,
+) -> std::pin::Pin<Box<Window>> {
+    let window = create(title, position, size, style, WindowKind::Modal, owner.hwnd);
+    unsafe { EnableWindow(owner.hwnd, 0) };
+    window
+}
+
+///Only windows made by `create` have a `Window` in `GWLP_USERDATA`.
+///Message boxes, dialogs and IME windows store their own data there.
+unsafe fn is_mini_window(hwnd: isize) -> bool {
+    let mut class = [0u16; 64];
+    let len = GetClassNameW(hwnd, class.as_mut_ptr(), class.len() as i32);
+    let class = &class[..len.max(0) as usize];
+    let prefix = CLASS_PREFIX.encode_utf16();
+    class.len() > CLASS_PREFIX.len() && prefix.zip(class).all(|(a, b)| a == *b)
+}
+
+///Closes every tooltip owned by `owner`.
+unsafe fn close_tooltips(owner: isize) {
+    unsafe extern "system" fn close(hwnd: isize, owner: isize) -> BOOL {
+        if GetWindow(hwnd, GW_OWNER) == owner && is_mini_window(hwnd) {
+            let ptr = GetWindowLongPtrW(hwnd, GWLP_USERDATA) as *const Window;
+            if ptr.as_ref().is_some_and(|w| w.kind == WindowKind::Tooltip) {
+                DestroyWindow(hwnd);
+            }
+        }
+        1
+    }
+    EnumThreadWindows(GetCurrentThreadId(), Some(close), owner);
+}
+
+fn create<U: Unit>(
+    title: &str,
+    position: Option<PhysicalPosition>,
+    size: Size<U>,
+    style: WindowStyle,
+    kind: WindowKind,
+    //The parent of child windows, or the owner of everything else.
+    parent: isize,
 ) -> std::pin::Pin<Box<Window>> {
     unsafe {
         if SetThreadDpiAwarenessContext(DPI_AWARENESS_CONTEXT_PER_MONITOR_AWARE_V2) == 0 {
             panic!("Only Windows 10 (1607) or later is supported.")
         };
 
-        //Title must be null terminated.
-        let title = std::ffi::CString::new(title).unwrap();
+        if let Err(err) = style.validate() {
+            panic!("Invalid window style: {}", err);
+        }
+        let (win32_style, win32_exstyle) = style.win32();
+
+        //Every window gets it's own class, titles aren't unique.
+        let class_name = to_wide(&format!(
+            "{}{}",
+            CLASS_PREFIX,
+            WINDOW_CLASS_COUNT.fetch_add(1, Relaxed)
+        ));
+        let title = to_wide(title);
 
-        let wnd_class = WNDCLASSA {
+        let wnd_class = WNDCLASSW {
             style: 0,
             wnd_proc: Some(wnd_proc),
             cls_extra: 0,
@@ -27,76 +182,102 @@ pub fn create_window(
             //Prevent cursor from changing when loading.
             cursor: LoadCursorW(null_mut(), IDC_ARROW) as isize,
             background: 0,
-            menu_name: core::mem::zeroed(),
-            class_name: title.as_ptr() as *const u8,
+            menu_name: null(),
+            class_name: class_name.as_ptr(),
         };
 
-        //Adjust the rect to fit exactly what the user requested.
-        //Windows has padding and other weird nonsense when trying set the width and height.
-        //Not needed anymore?
-
-        // let mut rect = RECT {
-        //     left: 0,
-        //     top: 0,
-        //     right: width as i32,
-        //     bottom: height as i32,
-        // };
-        // AdjustWindowRectEx(&mut rect, style.style, 0, style.exstyle);
-
-        RegisterClassA(&wnd_class);
-
-        let hwnd = CreateWindowExA(
-            style.exstyle,
-            title.as_ptr() as *const u8,
-            title.as_ptr() as *const u8,
-            style.style,
-            if x == 0 { CW_USEDEFAULT } else { x },
-            if y == 0 { CW_USEDEFAULT } else { y },
-            // CW_USEDEFAULT,
-            // CW_USEDEFAULT,
-            //These are adjusted later for DPI scaling.
-            width,
-            height,
-            0,
+        RegisterClassW(&wnd_class);
+
+        let position = position.unwrap_or(Point::new(CW_USEDEFAULT, CW_USEDEFAULT));
+        let hwnd = CreateWindowExW(
+            win32_exstyle,
+            class_name.as_ptr(),
+            title.as_ptr(),
+            win32_style,
+            position.x,
+            position.y,
+            //The DPI isn't known until the window is on a monitor, this is set below.
+            CW_USEDEFAULT,
+            CW_USEDEFAULT,
+            parent,
             0,
             0,
             null(),
         );
+        assert_ne!(hwnd, 0);
 
         //Get the display scale factor 1.0, 1.25, 1.5, 1.75, can also be custom.
-        let scale = GetDpiForWindow(hwnd) as f32 / DEFAULT_DPI;
-        let mut area = get_client_rect(hwnd);
+        let dpi = GetDpiForWindow(hwnd);
+        let scale = dpi as f32 / DEFAULT_DPI;
 
-        //Scale the size of the window to match the display scale.
-        //AdjustWindowRect used to be needed, but isn't anymore, I'm not sure why?
-        if scale != 1.0 {
-            SetWindowPos(
-                hwnd,
-                0,
-                area.x as i32,
-                area.y as i32,
-                (area.width as f32 * scale) as i32,
-                (area.height as f32 * scale) as i32,
-                SWP_FRAMECHANGED,
-            );
-            //Update the area since SetWindowPos will change it.
-            area = get_client_rect(hwnd);
-        }
+        //`size` is the client area, add the frame at the window's DPI.
+        let client = size.to_physical(scale);
+        let mut rect = RECT {
+            left: 0,
+            top: 0,
+            right: client.width,
+            bottom: client.height,
+        };
+        AdjustWindowRectExForDpi(
+            &mut rect,
+            GetWindowLongPtrW(hwnd, GWL_STYLE) as u32,
+            0,
+            GetWindowLongPtrW(hwnd, GWL_EXSTYLE) as u32,
+            dpi,
+        );
+        SetWindowPos(
+            hwnd,
+            0,
+            0,
+            0,
+            rect.right - rect.left,
+            rect.bottom - rect.top,
+            SWP_FRAMECHANGED | SWP_NOMOVE | SWP_NOZORDER | SWP_NOACTIVATE,
+        );
 
-        assert_ne!(hwnd, 0);
+        //Windows can make it smaller than requested, for example to fit on the monitor.
+        let mut client = RECT::default();
+        GetClientRect(hwnd, &mut client);
+        let buffer_size = IRect::from(client).size();
         let dc = GetDC(hwnd);
 
         //Safety: This *should* be pinned.
         let window = Box::pin(Window {
-            //Re-grab the area after calling SetWindowPos.
-            area,
+            buffer_size,
             hwnd,
             dc,
             display_scale: scale,
-            buffer: vec![0u32; area.width * area.height],
-            bitmap: BITMAPINFO::new(area.width as i32, area.height as i32),
+            buffer: vec![0u32; buffer_size.width as usize * buffer_size.height as usize],
+            bitmap: BITMAPINFO::new(buffer_size.width, buffer_size.height),
             quit: false,
-            mouse_position: Rect::default(),
+            destroyed: false,
+            proxy_queue: Arc::new(Mutex::new(ProxyQueue::default())),
+            style,
+            kind,
+            owner: parent,
+            class_name,
+            events: RefCell::new(VecDeque::new()),
+            mouse_position: Point::default(),
+            mouse_inside: false,
+            synthesize_mouse: true,
+            gestures: GestureRecognizer::new(),
+            drop_target: null_mut(),
+            drop_position: (0, 0),
+            gamepads: RefCell::new(Vec::new()),
+            fullscreen: None,
+            windowed: None,
+            min_size: None,
+            max_size: None,
+            aspect_ratio: None,
+            custom_frame: false,
+            resize_border: 0,
+            hit_regions: Vec::new(),
+            hit_test: None,
+            layered: None,
+            frame_callback: None,
+            in_size_move: false,
+            icon: None,
+            taskbar: None,
             left_mouse: MouseButtonState::new(),
             right_mouse: MouseButtonState::new(),
             middle_mouse: MouseButtonState::new(),
@@ -120,9 +301,57 @@ pub struct Window {
     pub dc: *mut c_void,
     pub buffer: Vec<u32>,
     pub bitmap: BITMAPINFO,
-    pub area: Rect,
+    ///Size of `buffer`, the client size as of the last `WM_SIZE`.
+    pub(crate) buffer_size: PhysicalSize,
     pub quit: bool,
-    pub mouse_position: Rect,
+    ///Set by `WM_NCDESTROY`, the handle must not be used after this.
+    pub destroyed: bool,
+    ///Shared with every `EventProxy`.
+    pub(crate) proxy_queue: Arc<Mutex<ProxyQueue>>,
+    pub style: WindowStyle,
+    pub kind: WindowKind,
+    ///Unregistered when the window is dropped.
+    pub class_name: Vec<u16>,
+    ///The parent of a child window or the owner of any other kind, zero for top-level windows.
+    pub owner: isize,
+    ///Events generated inside of `wnd_proc`.
+    pub(crate) events: RefCell<VecDeque<Event>>,
+    ///Client coordinates, negative when the mouse is captured and left of or above the window.
+    pub mouse_position: Point,
+    pub mouse_inside: bool,
+    ///Windows generates mouse messages for touch and pen input by default.
+    ///Disable this if you're already handling `Event::Touch` and `Event::Pen`.
+    pub synthesize_mouse: bool,
+    pub gestures: GestureRecognizer,
+    pub drop_target: *mut DropTarget,
+    ///Where files are being hovered or were dropped, in client coordinates.
+    pub drop_position: (i32, i32),
+    pub gamepads: RefCell<Vec<Box<dyn GamepadSource>>>,
+    pub fullscreen: Option<Fullscreen>,
+    ///Style and placement from before going fullscreen.
+    pub windowed: Option<Windowed>,
+    ///Client size, see `Window::set_min_size`.
+    pub min_size: Option<PhysicalSize>,
+    ///Client size, see `Window::set_max_size`.
+    pub max_size: Option<PhysicalSize>,
+    ///Client width divided by height.
+    pub aspect_ratio: Option<f32>,
+    ///The client area covers the whole window, see `Window::set_custom_frame`.
+    pub custom_frame: bool,
+    ///Thickness of the resize edges in logical units.
+    pub resize_border: i32,
+    ///Checked in order, the first region containing the cursor wins.
+    pub hit_regions: Vec<(Rect, HitTest)>,
+    pub hit_test: Option<HitTestCallback>,
+    ///Premultiplied copy of the buffer for transparent windows.
+    pub layered: Option<LayeredSurface>,
+    ///See `Window::set_frame_callback`.
+    pub frame_callback: Option<FrameCallback>,
+    ///The user is dragging the title bar or an edge.
+    pub in_size_move: bool,
+    ///Kept alive until it's replaced, `WM_SETICON` doesn't copy it.
+    pub icon: Option<Icon>,
+    pub taskbar: Option<Taskbar>,
     pub left_mouse: MouseButtonState,
     pub right_mouse: MouseButtonState,
     pub middle_mouse: MouseButtonState,
@@ -130,34 +359,220 @@ pub struct Window {
     pub mouse_5: MouseButtonState,
 }
 
+impl Drop for Window {
+    fn drop(&mut self) {
+        unsafe {
+            if !self.destroyed {
+                //Dropping the window shouldn't end the thread's message loop.
+                self.quit = true;
+                //Cleanup happens in `WM_DESTROY` and `WM_NCDESTROY`.
+                self.close();
+            }
+
+            UnregisterClassW(self.class_name.as_ptr(), 0);
+        }
+    }
+}
+
+///Draws a frame while `Window::event` is stuck in the modal move/size loop.
+pub struct FrameCallback(pub Box<dyn FnMut(&mut Window)>);
+
+impl core::fmt::Debug for FrameCallback {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("FrameCallback(..)")
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum WindowKind {
+    TopLevel,
+    ///See `create_child_window`.
+    Child,
+    ///See `create_owned_window`.
+    Owned,
+    ///See `create_popup`.
+    Popup,
+    ///See `create_tooltip`.
+    Tooltip,
+    ///See `create_modal_window`.
+    Modal,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Fullscreen {
+    ///Covers the monitor the window is on, the display mode is left alone.
+    Borderless,
+    ///Changes the display mode of the monitor the window is on.
+    ///Falls back to borderless if the mode is not supported.
+    Exclusive(VideoMode),
+}
+
+#[derive(Debug, Clone)]
+pub struct Windowed {
+    pub style: isize,
+    pub ex_style: isize,
+    pub placement: WINDOWPLACEMENT,
+    ///Set when the display mode was changed and needs to be restored.
+    pub device: Option<[u16; 32]>,
+}
+
 impl Window {
-    ///Updates the width and height based on the display scale.
-    pub fn rescale_window(&self) {
-        let area = self.client_area();
-        let (width, height) = if self.display_scale == 1.0 {
-            (
-                area.width as f32 / self.display_scale,
-                area.height as f32 / self.display_scale,
+    ///Resizes the window for the monitor's current scale if `WM_DPICHANGED` was missed,
+    ///keeping the logical client size. Does nothing if the scale hasn't changed.
+    pub fn rescale_window(&mut self) {
+        let scale = unsafe { GetDpiForWindow(self.hwnd) } as f32 / DEFAULT_DPI;
+        if scale == self.display_scale {
+            return;
+        }
+        let size = self.inner_size().to_logical(self.display_scale);
+        self.set_display_scale(scale);
+        self.set_inner_size(size);
+    }
+    ///Also rescales the size limits, which are in physical pixels.
+    fn set_display_scale(&mut self, scale: f32) {
+        let ratio = scale / self.display_scale;
+        let rescale = |size: PhysicalSize| {
+            PhysicalSize::new(
+                (size.width as f32 * ratio).round() as i32,
+                (size.height as f32 * ratio).round() as i32,
             )
-        } else {
-            (
-                area.width as f32 * self.display_scale,
-                area.height as f32 * self.display_scale,
+        };
+        self.min_size = self.min_size.map(rescale);
+        self.max_size = self.max_size.map(rescale);
+        self.display_scale = scale;
+    }
+    ///Size of the client area.
+    pub fn inner_size(&self) -> PhysicalSize {
+        let mut rect = RECT::default();
+        let _ = unsafe { GetClientRect(self.hwnd, &mut rect) };
+        IRect::from(rect).size()
+    }
+    ///Resizes the window so the client area is `size`.
+    ///
+    ///```no_run
+    ///# use window::*;
+    ///let window = create_window("Window", None, LogicalSize::new(600, 400), WindowStyle::DEFAULT);
+    ///window.set_inner_size(LogicalSize::new(800, 600));
+    ///```
+    pub fn set_inner_size<U: Unit>(&self, size: Size<U>) {
+        let outer = self.window_size(size.to_physical(self.display_scale));
+        unsafe {
+            SetWindowPos(
+                self.hwnd,
+                0,
+                0,
+                0,
+                outer.width,
+                outer.height,
+                SWP_NOMOVE | SWP_NOZORDER | SWP_NOACTIVATE,
             )
         };
-
+    }
+    ///Top left of the window frame in screen coordinates, or relative to the parent for child windows.
+    pub fn outer_position(&self) -> PhysicalPosition {
+        let rect = IRect::from(get_window_rect(self.hwnd));
+        if self.kind != WindowKind::Child {
+            return rect.position();
+        }
+        let mut point = POINT::from(rect.position());
+        unsafe { ScreenToClient(self.owner, &mut point) };
+        point.into()
+    }
+    ///Logical positions are converted with this window's display scale.
+    pub fn set_outer_position<U: Unit>(&self, position: Point<U>) {
+        let position = position.to_physical(self.display_scale);
         unsafe {
             SetWindowPos(
                 self.hwnd,
                 0,
-                area.x as i32,
-                area.y as i32,
-                width as i32,
-                height as i32,
-                SWP_FRAMECHANGED,
+                position.x,
+                position.y,
+                0,
+                0,
+                SWP_NOSIZE | SWP_NOZORDER | SWP_NOACTIVATE,
             )
         };
     }
+    ///Destroys the window, `Event::Quit` is returned afterwards.
+    ///Dropping the window will also close it.
+    pub fn close(&mut self) {
+        unsafe {
+            //The owner must be enabled before the modal window is destroyed,
+            //otherwise another application gets activated.
+            if self.kind == WindowKind::Modal {
+                EnableWindow(self.owner, 1);
+            }
+            if !self.destroyed {
+                DestroyWindow(self.hwnd);
+            }
+        }
+    }
+    ///Moves the window back onto the nearest monitor if the top of it can't be grabbed,
+    ///for example after the monitor it was on was unplugged.
+    ///This happens automatically when the displays change.
+    pub fn keep_on_screen(&mut self) {
+        unsafe {
+            //Windows moves minimized and maximized windows itself, children move with their parent.
+            if self.kind == WindowKind::Child
+                || IsIconic(self.hwnd) != 0
+                || IsZoomed(self.hwnd) != 0
+            {
+                return;
+            }
+
+            match self.fullscreen {
+                //Cover whichever monitor the window ended up on.
+                Some(Fullscreen::Borderless) => {
+                    return self.cover_monitor(SWP_NOZORDER | SWP_NOACTIVATE)
+                }
+                //Changing the display mode sends `WM_DISPLAYCHANGE`, so don't change it again.
+                Some(Fullscreen::Exclusive(_)) => return,
+                None => {}
+            }
+
+            //A strip along the top, where the title bar usually is, must be on a monitor.
+            let rect = IRect::from(get_window_rect(self.hwnd));
+            let min = (MIN_VISIBLE as f32 * self.display_scale).round() as i32;
+            let top = IRect::new(rect.x, rect.y, rect.width, rect.height.min(min));
+            //Called from `WM_DISPLAYCHANGE`, so only the rects are looked up.
+            let visible = work_areas().into_iter().any(|work_area| {
+                work_area
+                    .intersection(top)
+                    .is_some_and(|r| r.width >= top.width.min(min) && r.height >= top.height)
+            });
+            if visible {
+                return;
+            }
+
+            let Some(info) = window_monitor_info(self.hwnd) else {
+                return;
+            };
+            let rect = rect.clamp(IRect::from(info.rcWork));
+            SetWindowPos(
+                self.hwnd,
+                0,
+                rect.x,
+                rect.y,
+                rect.width,
+                rect.height,
+                SWP_NOZORDER | SWP_NOACTIVATE,
+            );
+        }
+    }
+    ///The monitor the window is mostly on, `None` if it was removed while looking it up.
+    pub fn current_monitor(&self) -> Option<Monitor> {
+        Monitor::from_handle(unsafe { MonitorFromWindow(self.hwnd, MONITOR_DEFAULTTONEAREST) })
+    }
+    ///Used to send events to this window from other threads.
+    pub fn proxy(&self) -> EventProxy {
+        EventProxy {
+            hwnd: self.hwnd,
+            queue: self.proxy_queue.clone(),
+        }
+    }
+    pub const fn id(&self) -> WindowId {
+        WindowId(self.hwnd)
+    }
     pub const fn display_scale(&self) -> f32 {
         self.display_scale
     }
@@ -167,85 +582,563 @@ impl Window {
         let _ = unsafe { GetClientRect(self.hwnd, &mut rect) };
         Rect::from_windows(rect)
     }
+    ///Size of `buffer`, this can lag behind `inner_size` until the next `WM_SIZE`.
     #[inline(always)]
-    pub const fn width(&self) -> usize {
-        self.area.width
-    }
-    #[inline(always)]
-    pub const fn height(&self) -> usize {
-        self.area.height
+    pub const fn buffer_size(&self) -> PhysicalSize {
+        self.buffer_size
     }
     pub fn borderless(&mut self) {
+        let style = self
+            .style
+            .decorations(false)
+            .resizable(false)
+            .minimize_button(false)
+            .maximize_button(false);
+        self.set_style(style).unwrap();
+    }
+    pub fn reset_style(&mut self) {
+        self.set_style(WindowStyle::DEFAULT).unwrap();
+    }
+    ///Changes the style of the window without moving or resizing it.
+    pub fn set_style(&mut self, style: WindowStyle) -> Result<(), StyleError> {
+        //Child windows and tooltips can't lose the flags they were created with.
+        let style = style.raw_flags_from(self.style);
+        style.validate()?;
+
+        let (mut win32_style, win32_exstyle) = style.win32();
+
         unsafe {
-            SetWindowLongPtrA(self.hwnd, GWL_STYLE, WindowStyle::BORDERLESS.style as isize);
+            //These are window state, not style. Visibility is changed with `SetWindowPos`.
+            let current = GetWindowLongPtrW(self.hwnd, GWL_STYLE) as u32;
+            win32_style &= !WS_VISIBLE;
+            win32_style |= current & (WS_MINIMIZE | WS_MAXIMIZE | WS_VISIBLE);
+
+            if self.custom_frame {
+                win32_style |=
+                    WS_CAPTION | WS_THICKFRAME | WS_SYSMENU | WS_MINIMIZEBOX | WS_MAXIMIZEBOX;
+            }
+
+            //Visibility and topmost can't be changed with `SetWindowLongPtr`.
+            let visible = if style.visible {
+                SWP_SHOWWINDOW
+            } else {
+                SWP_HIDEWINDOW
+            };
+            let (insert_after, zorder) = match (self.style.topmost, style.topmost) {
+                (false, true) => (HWND_TOPMOST, 0),
+                (true, false) => (HWND_NOTOPMOST, 0),
+                _ => (0, SWP_NOZORDER),
+            };
+
+            //Fullscreen windows pick up the new style when they leave fullscreen.
+            if let Some(windowed) = &mut self.windowed {
+                windowed.style = win32_style as isize;
+                windowed.ex_style = win32_exstyle as isize;
+            } else {
+                SetWindowLongPtrW(self.hwnd, GWL_STYLE, win32_style as isize);
+                SetWindowLongPtrW(self.hwnd, GWL_EXSTYLE, win32_exstyle as isize);
+            }
 
-            //Update the window area without moving or resizing it.
             SetWindowPos(
                 self.hwnd,
+                insert_after,
                 0,
                 0,
                 0,
                 0,
-                0,
-                SWP_FRAMECHANGED | SWP_NOMOVE | SWP_NOSIZE,
+                SWP_FRAMECHANGED | SWP_NOMOVE | SWP_NOSIZE | SWP_NOACTIVATE | zorder | visible,
             );
-        };
+        }
+
+        self.style = style;
+        Ok(())
+    }
+    ///`None` restores the style, size and position from before going fullscreen.
+    ///Undoes the display mode change from `Fullscreen::Exclusive`.
+    fn restore_display_mode(&mut self) {
+        if let Some(device) = self.windowed.as_mut().and_then(|w| w.device.take()) {
+            unsafe { ChangeDisplaySettingsExW(device.as_ptr(), null(), 0, 0, null_mut()) };
+        }
     }
-    pub fn set_pos(&mut self, x: usize, y: usize, width: usize, height: usize, flags: u32) {
+    pub fn set_fullscreen(&mut self, fullscreen: Option<Fullscreen>) {
+        unsafe {
+            //Put the display mode back before switching modes or leaving fullscreen.
+            self.restore_display_mode();
+
+            let Some(mode) = fullscreen else {
+                let Some(windowed) = self.windowed.take() else {
+                    return;
+                };
+
+                SetWindowLongPtrW(self.hwnd, GWL_STYLE, windowed.style);
+                SetWindowLongPtrW(self.hwnd, GWL_EXSTYLE, windowed.ex_style);
+                SetWindowPlacement(self.hwnd, &windowed.placement);
+                SetWindowPos(
+                    self.hwnd,
+                    0,
+                    0,
+                    0,
+                    0,
+                    0,
+                    SWP_FRAMECHANGED | SWP_NOMOVE | SWP_NOSIZE | SWP_NOZORDER | SWP_NOOWNERZORDER,
+                );
+                self.fullscreen = None;
+                return;
+            };
+
+            let Some(info) = window_monitor_info(self.hwnd) else {
+                return;
+            };
+
+            if self.windowed.is_none() {
+                let mut placement = WINDOWPLACEMENT::default();
+                GetWindowPlacement(self.hwnd, &mut placement);
+                self.windowed = Some(Windowed {
+                    style: GetWindowLongPtrW(self.hwnd, GWL_STYLE),
+                    ex_style: GetWindowLongPtrW(self.hwnd, GWL_EXSTYLE),
+                    placement,
+                    device: None,
+                });
+            }
+
+            let mut mode = mode;
+
+            if let Fullscreen::Exclusive(video_mode) = mode {
+                let devmode = video_mode.devmode();
+                let result = ChangeDisplaySettingsExW(
+                    info.szDevice.as_ptr(),
+                    &devmode,
+                    0,
+                    CDS_FULLSCREEN,
+                    null_mut(),
+                );
+
+                if result == DISP_CHANGE_SUCCESSFUL {
+                    if let Some(windowed) = &mut self.windowed {
+                        windowed.device = Some(info.szDevice);
+                    }
+                } else {
+                    mini::warn!(
+                        "Failed to change display mode: {:?}, error: {}",
+                        video_mode,
+                        result
+                    );
+                    mode = Fullscreen::Borderless;
+                }
+            }
+
+            let style = self.windowed.as_ref().unwrap().style as u32;
+            let style = (style & !WS_OVERLAPPEDWINDOW) | WS_POPUP;
+            SetWindowLongPtrW(self.hwnd, GWL_STYLE, style as isize);
+
+            self.cover_monitor(SWP_FRAMECHANGED | SWP_NOOWNERZORDER);
+            self.fullscreen = Some(mode);
+        }
+    }
+    ///Moves the window over the monitor it's mostly on.
+    fn cover_monitor(&self, flags: u32) {
+        let Some(info) = window_monitor_info(self.hwnd) else {
+            return;
+        };
+        let rect = info.rcMonitor;
         unsafe {
             SetWindowPos(
                 self.hwnd,
-                0,
-                x as i32,
-                y as i32,
-                width as i32,
-                height as i32,
+                HWND_TOP,
+                rect.left,
+                rect.top,
+                rect.right - rect.left,
+                rect.bottom - rect.top,
                 flags,
-            );
+            )
+        };
+    }
+    pub fn set_title(&self, title: &str) {
+        let title = to_wide(title);
+        unsafe { SetWindowTextW(self.hwnd, title.as_ptr()) };
+    }
+    ///Used for the title bar, taskbar and alt-tab. `None` restores the default icon.
+    pub fn set_icon(&mut self, icon: Option<Icon>) {
+        let handle = icon.as_ref().map(|icon| icon.handle).unwrap_or(null_mut());
+        unsafe {
+            SendMessageW(self.hwnd, WM_SETICON, ICON_SMALL, handle as isize);
+            SendMessageW(self.hwnd, WM_SETICON, ICON_BIG, handle as isize);
         }
+        self.icon = icon;
     }
-    pub fn reset_style(&mut self) {
+    fn taskbar(&mut self) -> Option<&Taskbar> {
+        if self.taskbar.is_none() {
+            self.taskbar = Taskbar::new();
+        }
+        self.taskbar.as_ref()
+    }
+    ///Shows progress on the taskbar button.
+    ///This does nothing until the taskbar button has been created.
+    pub fn set_taskbar_progress(&mut self, progress: TaskbarProgress) {
+        let hwnd = self.hwnd;
+        if let Some(taskbar) = self.taskbar() {
+            taskbar.set_progress(hwnd, progress);
+        }
+    }
+    ///A small badge in the corner of the taskbar button.
+    ///The description is read by screen readers.
+    pub fn set_taskbar_overlay(&mut self, icon: Option<&Icon>, description: &str) {
+        let hwnd = self.hwnd;
+        if let Some(taskbar) = self.taskbar() {
+            taskbar.set_overlay(hwnd, icon, description);
+        }
+    }
+    pub fn minimize(&self) {
+        unsafe { ShowWindow(self.hwnd, SW_MINIMIZE) };
+    }
+    pub fn maximize(&self) {
+        unsafe { ShowWindow(self.hwnd, SW_MAXIMIZE) };
+    }
+    ///Restores a minimized or maximized window to it's previous size and position.
+    pub fn restore(&self) {
+        unsafe { ShowWindow(self.hwnd, SW_RESTORE) };
+    }
+    ///Hidden windows don't show up in the taskbar or alt-tab.
+    pub fn set_visible(&mut self, visible: bool) {
+        unsafe { ShowWindow(self.hwnd, if visible { SW_SHOW } else { SW_HIDE }) };
+        //`set_style` shows or hides the window based on this.
+        self.style.visible = visible;
+    }
+    ///Brings the window to the front and gives it keyboard focus.
+    ///Windows will only allow this if the process received the last input event,
+    ///otherwise the taskbar button flashes instead.
+    pub fn focus(&self) {
+        unsafe {
+            if IsIconic(self.hwnd) != 0 {
+                ShowWindow(self.hwnd, SW_RESTORE);
+            }
+            SetForegroundWindow(self.hwnd);
+            SetFocus(self.hwnd);
+        }
+    }
+    ///Flashes the taskbar button until the window is focused.
+    pub fn request_attention(&self) {
+        let info = FLASHWINFO {
+            cbSize: size_of::<FLASHWINFO>() as u32,
+            hwnd: self.hwnd,
+            dwFlags: FLASHW_TRAY | FLASHW_TIMERNOFG,
+            uCount: 0,
+            dwTimeout: 0,
+        };
+        unsafe { FlashWindowEx(&info) };
+    }
+    pub fn is_maximized(&self) -> bool {
+        unsafe { IsZoomed(self.hwnd) != 0 }
+    }
+    pub fn is_minimized(&self) -> bool {
+        unsafe { IsIconic(self.hwnd) != 0 }
+    }
+    pub fn is_visible(&self) -> bool {
+        unsafe { IsWindowVisible(self.hwnd) != 0 }
+    }
+    pub fn is_focused(&self) -> bool {
+        unsafe { GetForegroundWindow() == self.hwnd }
+    }
+    ///Smallest client area the user can resize to.
+    ///The client area can never be smaller than 1x1 logical pixels.
+    ///Rescaled when the display scale changes, like the window.
+    pub fn set_min_size<U: Unit>(&mut self, size: Option<Size<U>>) {
+        self.min_size = size.map(|size| size.to_physical(self.display_scale));
+    }
+    ///Largest client area the user can resize to.
+    ///Rescaled when the display scale changes, like the window.
+    pub fn set_max_size<U: Unit>(&mut self, size: Option<Size<U>>) {
+        self.max_size = size.map(|size| size.to_physical(self.display_scale));
+    }
+    ///Keeps the client area at `width / height` while resizing, e.g. `Some(16.0 / 9.0)`.
+    pub fn set_aspect_ratio(&mut self, ratio: Option<f32>) {
+        assert!(ratio.is_none_or(|r| r > 0.0));
+        self.aspect_ratio = ratio;
+    }
+    ///Size of the borders and title bar, which is the window size minus the client size.
+    pub fn frame_size(&self) -> (i32, i32) {
+        self.frame_size_for_dpi(unsafe { GetDpiForWindow(self.hwnd) })
+    }
+    fn frame_size_for_dpi(&self, dpi: u32) -> (i32, i32) {
+        //The client area covers the frame.
+        if self.custom_frame {
+            return (0, 0);
+        }
+        let mut rect = RECT::default();
         unsafe {
-            SetWindowLongPtrA(self.hwnd, GWL_STYLE, WindowStyle::DEFAULT.style as isize);
+            let style = GetWindowLongPtrW(self.hwnd, GWL_STYLE) as u32;
+            let ex_style = GetWindowLongPtrW(self.hwnd, GWL_EXSTYLE) as u32;
+            AdjustWindowRectExForDpi(&mut rect, style, 0, ex_style, dpi);
+        }
+        (rect.right - rect.left, rect.bottom - rect.top)
+    }
+    ///Converts a client size into a window size.
+    fn window_size(&self, size: PhysicalSize) -> PhysicalSize {
+        let (frame_width, frame_height) = self.frame_size();
+        PhysicalSize::new(size.width + frame_width, size.height + frame_height)
+    }
+    ///Removes the native title bar and borders while keeping moving, snapping and resizing.
+    ///The app draws it's own title bar and describes it with `set_hit_regions` or `set_hit_test`.
+    pub fn set_custom_frame(&mut self, enabled: bool) {
+        self.custom_frame = enabled;
+        if enabled && self.resize_border == 0 {
+            self.resize_border = 6;
+        }
+
+        //Snapping and resizing only work when the window has a frame,
+        //`set_style` adds one back and it's hidden by returning 0 from `WM_NCCALCSIZE`.
+        self.set_style(self.style).unwrap();
+    }
+    ///Regions in client coordinates, such as the title bar and caption buttons.
+    pub fn set_hit_regions(&mut self, regions: Vec<(Rect, HitTest)>) {
+        self.hit_regions = regions;
+    }
+    pub fn set_hit_test(&mut self, callback: Option<HitTestCallback>) {
+        self.hit_test = callback;
+    }
+    ///Thickness of the resize edges in logical units, 0 disables resizing from the edges.
+    pub fn set_resize_border(&mut self, border: i32) {
+        self.resize_border = border;
+    }
+    ///What part of the window is at `(x, y)` in client coordinates.
+    pub fn hit(&self, x: i32, y: i32) -> Option<HitTest> {
+        if let Some(hit) = self.hit_test.and_then(|callback| callback(self, x, y)) {
+            return Some(hit);
+        }
+
+        if self.resize_border > 0 && !self.is_maximized() && self.fullscreen.is_none() {
+            let border = (self.resize_border as f32 * self.display_scale).round() as i32;
+            let area = self.client_area();
+            let edge = HitTest::edge(x, y, area.width as i32, area.height as i32, border);
+            if edge.is_some() {
+                return edge;
+            }
+        }
 
-            //Update the window area without moving or resizing it.
+        if x < 0 || y < 0 {
+            return None;
+        }
+
+        self.hit_regions
+            .iter()
+            .find(|(rect, _)| rect.contains(x as usize, y as usize))
+            .map(|(_, hit)| *hit)
+    }
+    ///Moves and resizes the window frame, `flags` are the `SWP_*` flags.
+    pub fn set_pos(&mut self, position: PhysicalPosition, size: PhysicalSize, flags: u32) {
+        unsafe {
             SetWindowPos(
                 self.hwnd,
                 0,
-                0,
-                0,
-                0,
-                0,
-                SWP_FRAMECHANGED | SWP_NOMOVE | SWP_NOSIZE,
+                position.x,
+                position.y,
+                size.width,
+                size.height,
+                flags,
             );
+        }
+    }
+    ///Keeps receiving mouse input when the cursor is outside of the window.
+    ///Useful for drags that leave the client area.
+    pub fn capture_mouse(&self) {
+        unsafe { SetCapture(self.hwnd) };
+    }
+    pub fn release_mouse(&self) {
+        unsafe {
+            if GetCapture() == self.hwnd {
+                ReleaseCapture();
+            }
         };
     }
+    ///Allow files to be dragged onto the window.
+    ///See `Event::FileHovered`, `Event::FileDropped` and `Event::HoverCancelled`.
+    ///
+    ///OLE talks to the drag source through a hidden window on this thread,
+    ///so after this `Window::event` dispatches messages for every window on the thread.
+    pub fn accept_files(&mut self) {
+        if !self.drop_target.is_null() {
+            return;
+        }
+
+        unsafe {
+            //OLE is needed to know when files are hovered, `WM_DROPFILES` only reports the drop.
+            //This fails if COM was already initialized as multithreaded on this thread.
+            if OleInitialize(null_mut()) >= 0 {
+                let target = DropTarget::new(self.hwnd);
+                if RegisterDragDrop(self.hwnd, target) == S_OK {
+                    self.drop_target = target;
+                    return;
+                }
+                drop(Box::from_raw(target));
+            }
+
+            mini::warn!("Falling back to WM_DROPFILES, hovering files will not be reported.");
+            DragAcceptFiles(self.hwnd, 1);
+        }
+    }
+    ///Controller events will be returned from `Window::event`.
+    ///`Window::event_blocking` only polls gamepads after a window message arrives.
+    pub fn add_gamepad(&self, source: impl GamepadSource + 'static) {
+        self.gamepads.borrow_mut().push(Box::new(source));
+    }
+    pub(crate) fn poll_gamepads(&self) -> Option<Event> {
+        poll_gamepads(&mut self.gamepads.borrow_mut())
+    }
+    ///Events queued by `wnd_proc` or sent through an `EventProxy`.
+    pub(crate) fn pending(&self) -> Option<Event> {
+        if let Some(event) = self.events.borrow_mut().pop_front() {
+            return Some(event);
+        }
+        self.proxy_queue.lock().unwrap().events.pop_front()
+    }
     pub fn event(&self) -> Option<Event> {
         if self.quit {
             return Some(Event::Quit);
         }
 
-        unsafe {
+        if let Some(event) = self.pending() {
+            return Some(event);
+        }
+
+        let event = unsafe {
             let mut msg = MSG::new();
-            let result = PeekMessageA(&mut msg, self.hwnd, 0, 0, PM_REMOVE);
-            translate_message(msg, result)
+            if self.drop_target.is_null() {
+                let result = PeekMessageW(&mut msg, self.hwnd, 0, 0, PM_REMOVE);
+                translate_message(msg, result)
+            } else {
+                let result = PeekMessageW(&mut msg, 0, 0, 0, PM_REMOVE);
+                self.translate_or_dispatch(msg, result)
+            }
+        };
+
+        //`wnd_proc` may have queued something while handling the message.
+        event
+            .or_else(|| self.pending())
+            .or_else(|| self.poll_gamepads())
+    }
+    ///Same as `Window::event` but takes messages for any window on the thread.
+    ///Messages left in the queue would wake `wait_for_message` straight away.
+    fn event_or_dispatch(&self) -> Option<Event> {
+        if self.quit {
+            return Some(Event::Quit);
         }
+
+        if let Some(event) = self.pending() {
+            return Some(event);
+        }
+
+        let event = unsafe {
+            let mut msg = MSG::new();
+            let result = PeekMessageW(&mut msg, 0, 0, 0, PM_REMOVE);
+            self.translate_or_dispatch(msg, result)
+        };
+
+        event
+            .or_else(|| self.pending())
+            .or_else(|| self.poll_gamepads())
+    }
+    ///Messages for other windows, such as OLE's drag and drop window, are dispatched.
+    unsafe fn translate_or_dispatch(&self, msg: MSG, result: i32) -> Option<Event> {
+        if result != 0 && msg.hwnd == self.hwnd {
+            return translate_message(msg, result);
+        }
+        //Thread messages like `WM_QUIT` don't have a window and are dropped.
+        if result != 0 {
+            TranslateMessage(&msg);
+            DispatchMessageW(&msg);
+        }
+        None
     }
     pub fn event_blocking(&self) -> Option<Event> {
         if self.quit {
             return Some(Event::Quit);
         }
 
-        unsafe {
+        if let Some(event) = self.pending() {
+            return Some(event);
+        }
+
+        let event = unsafe {
             let mut msg = MSG::new();
-            let result = GetMessageA(&mut msg, self.hwnd, 0, 0);
-            translate_message(msg, result)
+            //See `Window::accept_files`.
+            if self.drop_target.is_null() {
+                let result = GetMessageW(&mut msg, self.hwnd, 0, 0);
+                translate_message(msg, result)
+            } else {
+                let result = GetMessageW(&mut msg, 0, 0, 0);
+                self.translate_or_dispatch(msg, result)
+            }
+        };
+
+        event
+            .or_else(|| self.pending())
+            .or_else(|| self.poll_gamepads())
+    }
+    ///Waits for an event, returns `None` if nothing arrived in time.
+    ///Messages for other windows on this thread are dispatched while waiting,
+    ///use `EventLoop` to get their events as well.
+    pub fn event_timeout(&self, timeout: std::time::Duration) -> Option<Event> {
+        self.wait_until(std::time::Instant::now() + timeout)
+    }
+    ///Waits for an event, returns `None` once the deadline has passed.
+    pub fn wait_until(&self, deadline: std::time::Instant) -> Option<Event> {
+        loop {
+            if let Some(event) = self.event_or_dispatch() {
+                return Some(event);
+            }
+
+            let now = std::time::Instant::now();
+            if now >= deadline || !wait_for_message(deadline - now) {
+                return None;
+            }
         }
     }
+    ///Mouse input passes through to the windows underneath. The window must be transparent.
+    pub fn set_click_through(&mut self, click_through: bool) -> Result<(), StyleError> {
+        self.set_style(self.style.click_through(click_through))
+    }
+    fn draw_layered(&mut self) {
+        let (width, height) = (self.buffer_size.width, self.buffer_size.height);
+        if width == 0 || height == 0 {
+            return;
+        }
+
+        let surface = match &mut self.layered {
+            Some(surface) if surface.width == width && surface.height == height => surface,
+            layered => layered.insert(LayeredSurface::new(width, height)),
+        };
+        premultiply(&self.buffer, surface.pixels());
+
+        let size = SIZE {
+            cx: width,
+            cy: height,
+        };
+        let blend = BLENDFUNCTION {
+            BlendOp: AC_SRC_OVER,
+            BlendFlags: 0,
+            SourceConstantAlpha: 255,
+            AlphaFormat: AC_SRC_ALPHA,
+        };
+
+        unsafe {
+            UpdateLayeredWindow(
+                self.hwnd,
+                null_mut(),
+                null(),
+                &size,
+                surface.dc,
+                &POINT::default(),
+                0,
+                &blend,
+                ULW_ALPHA,
+            )
+        };
+    }
     pub fn vsync(&self) {
         unsafe { DwmFlush() };
     }
     //TODO: There is no support for depth.
+    ///Transparent windows treat the buffer as `0xAARRGGBB` with straight alpha.
     pub fn draw(&mut self) {
         // Not sure how to handle resets.
         // self.left_mouse.reset();
@@ -254,18 +1147,59 @@ impl Window {
         // self.mouse_4.reset();
         // self.mouse_5.reset();
 
+        if self.style.transparent {
+            return self.draw_layered();
+        }
+
+        self.blit(self.dc);
+    }
+    ///Windows doesn't return from `Window::event` while a window is being moved or resized.
+    ///The callback is run on a timer and after every resize until the user lets go,
+    ///it should fill the buffer, which is then drawn.
+    ///
+    ///```no_run
+    ///# use window::*;
+    ///let mut window = create_window("Window", None, LogicalSize::new(600, 400), WindowStyle::DEFAULT);
+    ///window.set_frame_callback(|window| window.buffer.fill(0x4fa3a8));
+    ///```
+    pub fn set_frame_callback(&mut self, callback: impl FnMut(&mut Window) + 'static) {
+        self.frame_callback = Some(FrameCallback(Box::new(callback)));
+    }
+    ///Runs the frame callback and draws the result.
+    fn live_frame(&mut self) {
+        //Taken out so the callback can borrow the window.
+        if let Some(mut callback) = self.frame_callback.take() {
+            (callback.0)(self);
+            //The callback may have set a new one.
+            if self.frame_callback.is_none() {
+                self.frame_callback = Some(callback);
+            }
+            self.draw();
+        }
+    }
+    ///Asks for `Event::RedrawRequested`, the current buffer is drawn straight away.
+    ///Multiple requests are merged until the window is painted.
+    pub fn request_redraw(&self) {
+        if self.style.transparent {
+            self.events.borrow_mut().push_back(Event::RedrawRequested);
+        } else {
+            unsafe { InvalidateRect(self.hwnd, null(), 0) };
+        }
+    }
+    ///Copies the buffer onto a device context.
+    fn blit(&self, dc: *mut c_void) {
         unsafe {
             StretchDIBits(
-                self.dc,
+                dc,
                 0,
                 0,
-                self.area.width as i32,
-                self.area.height as i32,
+                self.buffer_size.width,
+                self.buffer_size.height,
                 0,
                 0,
-                self.area.width as i32,
-                self.area.height as i32,
-                self.buffer.as_mut_ptr() as *const c_void,
+                self.buffer_size.width,
+                self.buffer_size.height,
+                self.buffer.as_ptr() as *const c_void,
                 &self.bitmap,
                 0,
                 SRCCOPY,
@@ -274,41 +1208,27 @@ impl Window {
     }
 }
 
-pub struct WindowStyle {
-    pub style: u32,
-    pub exstyle: u32,
-}
-
-impl WindowStyle {
-    pub const DEFAULT: Self = Self {
-        style: WS_CAPTION
-            | WS_SYSMENU
-            | WS_THICKFRAME
-            | WS_MINIMIZEBOX
-            | WS_MAXIMIZEBOX
-            | WS_VISIBLE,
-        exstyle: 0,
-    };
-
-    pub const BORDERLESS: Self = Self {
-        style: WS_POPUP | WS_VISIBLE,
-        exstyle: 0,
-    };
-
-    pub const fn ex_style(mut self, flags: u32) -> Self {
-        self.exstyle |= flags;
-        self
-    }
-
-    pub const fn style(mut self, flags: u32) -> Self {
-        self.style |= flags;
-        self
-    }
-}
+///Resizes the window rect from `WM_SIZING` so the client area keeps the aspect ratio.
+///The edge being dragged decides which side is adjusted.
+pub fn apply_aspect_ratio(rect: &mut RECT, edge: usize, ratio: f32, frame: (i32, i32)) {
+    let (frame_width, frame_height) = frame;
+    let width = (rect.right - rect.left - frame_width).max(1);
+    let height = (rect.bottom - rect.top - frame_height).max(1);
 
-impl Default for WindowStyle {
-    fn default() -> Self {
-        Self::DEFAULT
+    match edge {
+        //Dragging the top or bottom changes the height, so the width follows.
+        WMSZ_TOP | WMSZ_BOTTOM => {
+            let width = (height as f32 * ratio).round() as i32;
+            rect.right = rect.left + width + frame_width;
+        }
+        _ => {
+            let height = (width as f32 / ratio).round() as i32;
+            if edge == WMSZ_TOPLEFT || edge == WMSZ_TOPRIGHT {
+                rect.top = rect.bottom - height - frame_height;
+            } else {
+                rect.bottom = rect.top + height + frame_height;
+            }
+        }
     }
 }
 
@@ -325,7 +1245,7 @@ pub unsafe extern "system" fn wnd_proc(
 
     let ptr = GetWindowLongPtrW(hwnd, GWLP_USERDATA) as *mut Window;
     if ptr.is_null() {
-        return DefWindowProcA(hwnd, msg, wparam, lparam);
+        return DefWindowProcW(hwnd, msg, wparam, lparam);
     }
 
     //I'm not convinced this is the right way to do this.
@@ -337,26 +1257,176 @@ pub unsafe extern "system" fn wnd_proc(
     match msg {
         //We can choose not to destroy the window, for example with a save prompt.
         WM_CLOSE => {
-            assert!(DestroyWindow(hwnd) != 0);
+            window.events.get_mut().push_back(Event::CloseRequested);
             return 0;
         }
         WM_DESTROY => {
-            PostQuitMessage(0);
+            //The owner might be destroyed first.
+            if window.kind == WindowKind::Modal {
+                EnableWindow(window.owner, 1);
+            }
+            //OLE keeps a reference to the drop target until it's revoked,
+            //which has to happen while the handle is still valid.
+            if !window.drop_target.is_null() {
+                RevokeDragDrop(hwnd);
+                DropTarget::release(window.drop_target);
+                window.drop_target = null_mut();
+            }
+            //Exclusive fullscreen would otherwise keep the lower resolution.
+            window.restore_display_mode();
+            //Only the main window should end the thread's message loop.
+            //`quit` is already set when the window is being dropped.
+            if window.kind == WindowKind::TopLevel && !window.quit {
+                PostQuitMessage(0);
+            }
             window.quit = true;
             return 0;
         }
+        //The last message the window gets, the handle is invalid after this.
+        WM_NCDESTROY => {
+            ReleaseDC(hwnd, window.dc);
+            window.dc = null_mut();
+            //`wnd_proc` and the drop target must not touch the window after it's freed.
+            SetWindowLongPtrW(hwnd, GWLP_USERDATA, 0);
+            window.destroyed = true;
+            window.proxy_queue.lock().unwrap().closed = true;
+            return DefWindowProcW(hwnd, msg, wparam, lparam);
+        }
+        //Sent to every top-level window when monitors are added, removed or changed.
+        WM_DISPLAYCHANGE => {
+            window.keep_on_screen();
+            return DefWindowProcW(hwnd, msg, wparam, lparam);
+        }
+        WM_ACTIVATE => {
+            if (wparam & 0xffff) as u16 == WA_INACTIVE {
+                close_tooltips(hwnd);
+                //Clicking outside of a popup moves the focus somewhere else.
+                if window.kind == WindowKind::Popup {
+                    DestroyWindow(hwnd);
+                }
+            }
+            return DefWindowProcW(hwnd, msg, wparam, lparam);
+        }
+        WM_NCLBUTTONDOWN | WM_NCRBUTTONDOWN => {
+            close_tooltips(hwnd);
+            return DefWindowProcW(hwnd, msg, wparam, lparam);
+        }
         //TODO: Could add a feature flag to skip this for no GDI use.
         //Do it in the UI library for now?
         WM_SIZE => {
             let (width, height) = (low, high);
+
+            //Keep the old buffer around, a zero sized bitmap can't be drawn.
+            if wparam == SIZE_MINIMIZED || width == 0 || height == 0 {
+                return 0;
+            }
+
             mini::info!("Resizing to width: {}, height: {}", width, height);
-            window.buffer.clear();
-            window.buffer.resize(width * height, 0);
-            window.bitmap = BITMAPINFO::new(width as i32, height as i32);
-            window.area = Rect::new(0, 0, width, height);
+            let size = PhysicalSize::new(width as i32, height as i32);
+            resize_buffer(&mut window.buffer, window.buffer_size, size);
+            window.bitmap = BITMAPINFO::new(size.width, size.height);
+            window.buffer_size = size;
+
+            //Windows only invalidates the newly exposed area, redraw everything with the new bitmap.
+            InvalidateRect(hwnd, null(), 0);
 
+            //Don't wait for the timer, otherwise the exposed area stays black.
+            if window.in_size_move {
+                window.live_frame();
+            }
             return 0;
         }
+        WM_ENTERSIZEMOVE => {
+            window.in_size_move = true;
+            if window.frame_callback.is_some() {
+                SetTimer(hwnd, LIVE_RESIZE_TIMER, LIVE_RESIZE_INTERVAL_MS, null());
+            }
+            return 0;
+        }
+        WM_EXITSIZEMOVE => {
+            window.in_size_move = false;
+            KillTimer(hwnd, LIVE_RESIZE_TIMER);
+            return 0;
+        }
+        WM_TIMER if wparam == LIVE_RESIZE_TIMER => {
+            window.live_frame();
+            return 0;
+        }
+        //Layered windows are never sent this, see `Window::draw_layered`.
+        WM_PAINT => {
+            //Show the last frame until the app draws again, instead of whatever was there.
+            let mut paint = PAINTSTRUCT::default();
+            let dc = BeginPaint(hwnd, &mut paint);
+            if !dc.is_null() {
+                window.blit(dc);
+            }
+            EndPaint(hwnd, &paint);
+
+            let events = window.events.get_mut();
+            if events.back() != Some(&Event::RedrawRequested) {
+                events.push_back(Event::RedrawRequested);
+            }
+            return 0;
+        }
+        WM_NCCALCSIZE if window.custom_frame && wparam != 0 => {
+            //Returning 0 without changing the rect makes the client area the whole window.
+            //Maximized windows hang over the edge of the monitor by the frame size, so remove it.
+            if IsZoomed(hwnd) != 0 {
+                let params = &mut *(lparam as *mut NCCALCSIZE_PARAMS);
+                let dpi = GetDpiForWindow(hwnd);
+                let x = GetSystemMetricsForDpi(SM_CXFRAME, dpi)
+                    + GetSystemMetricsForDpi(SM_CXPADDEDBORDER, dpi);
+                let y = GetSystemMetricsForDpi(SM_CYFRAME, dpi)
+                    + GetSystemMetricsForDpi(SM_CXPADDEDBORDER, dpi);
+                let rect = &mut params.rgrc[0];
+                rect.left += x;
+                rect.top += y;
+                rect.right -= x;
+                rect.bottom -= y;
+            }
+            return 0;
+        }
+        WM_NCHITTEST => {
+            //These are signed screen coordinates.
+            let mut point = POINT {
+                x: (lparam & 0xffff) as i16 as i32,
+                y: ((lparam >> 16) & 0xffff) as i16 as i32,
+            };
+            ScreenToClient(hwnd, &mut point);
+
+            match window.hit(point.x, point.y) {
+                Some(hit) => return hit.code(),
+                None if window.custom_frame => return HTCLIENT,
+                None => return DefWindowProcW(hwnd, msg, wparam, lparam),
+            }
+        }
+        WM_GETMINMAXINFO => {
+            let info = &mut *(lparam as *mut MINMAXINFO);
+            let smallest = LogicalSize::new(1, 1).to_physical(window.display_scale);
+            let min = window.window_size(window.min_size.unwrap_or(smallest));
+            info.ptMinTrackSize = POINT {
+                x: min.width.max(info.ptMinTrackSize.x),
+                y: min.height.max(info.ptMinTrackSize.y),
+            };
+
+            if let Some(max) = window.max_size {
+                let max = window.window_size(max);
+                info.ptMaxTrackSize = POINT {
+                    x: max.width,
+                    y: max.height,
+                };
+            }
+            return 0;
+        }
+        WM_SIZING => {
+            let Some(ratio) = window.aspect_ratio else {
+                return DefWindowProcW(hwnd, msg, wparam, lparam);
+            };
+
+            let rect = &mut *(lparam as *mut RECT);
+            apply_aspect_ratio(rect, wparam, ratio, window.frame_size());
+            return 1;
+        }
         //https://learn.microsoft.com/en-us/windows/win32/hidpi/wm-dpichanged
         WM_DPICHANGED => {
             //The new display scale and DPI.
@@ -370,36 +1440,90 @@ pub unsafe extern "system" fn wnd_proc(
             assert!(!ptr.is_null());
             let rect = &(*ptr);
 
-            let old = window.client_area();
-            let original_width = old.width as f32 / window.display_scale;
-            let original_height = old.height as f32 / window.display_scale;
+            //Keep the logical client size, the frame is added back at the new DPI.
+            //Scaling the window size instead makes the client area drift.
+            let logical = window.inner_size().to_logical(window.display_scale);
+            let size = logical.to_physical(scale);
+            let (frame_width, frame_height) = window.frame_size_for_dpi(dpi as u32);
 
-            let (width, height) = if scale == 1.0 {
-                (original_width, original_height)
-            } else {
-                (original_width * scale, original_height * scale)
-            };
+            mini::info!("Rescaling Window x: {}, y: {}, width: {}, height: {}, old_scale: {}, new_scale: {}", rect.left, rect.top, size.width, size.height, window.display_scale, scale);
 
-            mini::info!("Rescaling Window x: {}, y: {}, width: {}, height: {}, old_scale: {}, new_scale: {}", old.x, old.y, width.round(), height.round(), window.display_scale, scale);
+            //`WM_GETMINMAXINFO` is sent during `SetWindowPos` and needs the new scale.
+            window.set_display_scale(scale);
 
             SetWindowPos(
                 hwnd,
                 0,
                 rect.left,
                 rect.top,
-                width.round() as i32,
-                height.round() as i32,
+                size.width + frame_width,
+                size.height + frame_height,
                 SWP_NOZORDER | SWP_NOACTIVATE,
             );
 
-            window.display_scale = scale;
             return 0;
         }
         WM_MOUSEMOVE => {
-            window.mouse_position = Rect::new(low, high, 1, 1);
+            window.mouse_position = Point::from_lparam(lparam);
+
+            //`WM_MOUSELEAVE` is only sent once per call to `TrackMouseEvent`.
+            if !window.mouse_inside {
+                let mut track = TRACKMOUSEEVENT::new(hwnd, TME_LEAVE);
+                TrackMouseEvent(&mut track);
+                window.mouse_inside = true;
+                window.events.get_mut().push_back(Event::MouseEnter);
+            }
+            return 0;
+        }
+        WM_MOUSELEAVE => {
+            window.mouse_inside = false;
+            window.events.get_mut().push_back(Event::MouseLeave);
+            return 0;
+        }
+        WM_POINTERDOWN | WM_POINTERUPDATE | WM_POINTERUP => {
+            let Some(event) = pointer_event(hwnd, wparam) else {
+                return DefWindowProcW(hwnd, msg, wparam, lparam);
+            };
+
+            if let Event::Touch { id, phase, x, y } = event {
+                let gestures = window.gestures.update(TouchPoint {
+                    id,
+                    phase,
+                    x: x as f32,
+                    y: y as f32,
+                    time: GetMessageTime() as u32,
+                });
+                window.events.get_mut().push_back(event);
+                window.events.get_mut().extend(gestures);
+            } else {
+                window.events.get_mut().push_back(event);
+            }
+
+            //`DefWindowProc` is what generates the mouse messages.
+            if window.synthesize_mouse {
+                return DefWindowProcW(hwnd, msg, wparam, lparam);
+            }
+            return 0;
+        }
+        WM_MOUSEWHEEL | WM_MOUSEHWHEEL => {
+            let delta = (wparam >> 16) as i16;
+            let control = wparam.low() & MK_CONTROL != 0;
+            if let Some(event) = wheel_gesture(delta, control, msg == WM_MOUSEHWHEEL) {
+                window.events.get_mut().push_back(event);
+            }
+            return 0;
+        }
+        WM_DROPFILES => {
+            let hdrop = wparam as *mut c_void;
+            if let Some((pt, files)) = read_hdrop(hdrop) {
+                window.drop_position = (pt.x, pt.y);
+                window.events.get_mut().push_back(Event::FileDropped(files));
+            }
+            DragFinish(hdrop);
             return 0;
         }
         WM_LBUTTONDOWN => {
+            close_tooltips(hwnd);
             window.left_mouse.pressed(Rect::new(low, high, 1, 1));
             return 0;
         }
@@ -408,6 +1532,7 @@ pub unsafe extern "system" fn wnd_proc(
             return 0;
         }
         WM_RBUTTONDOWN => {
+            close_tooltips(hwnd);
             window.right_mouse.pressed(Rect::new(low, high, 1, 1));
             return 0;
         }
@@ -416,6 +1541,7 @@ pub unsafe extern "system" fn wnd_proc(
             return 0;
         }
         WM_MBUTTONDOWN => {
+            close_tooltips(hwnd);
             window.middle_mouse.pressed(Rect::new(low, high, 1, 1));
             return 0;
         }
@@ -441,6 +1567,67 @@ pub unsafe extern "system" fn wnd_proc(
             }
             return 0;
         }
-        _ => return DefWindowProcA(hwnd, msg, wparam, lparam),
+        _ => return DefWindowProcW(hwnd, msg, wparam, lparam),
+    }
+}
+
+///Keeps the pixels that are still inside the window, so resizing doesn't flash black
+///before the app draws again. The newly exposed area is black.
+fn resize_buffer(buffer: &mut Vec<u32>, old: PhysicalSize, new: PhysicalSize) {
+    let (old_width, width, height) = (old.width as usize, new.width as usize, new.height as usize);
+    let columns = old_width.min(width);
+    //The buffer is public, so it might not match the old size.
+    let rows = (old.height as usize)
+        .min(height)
+        .min(buffer.len().checked_div(old_width).unwrap_or(0));
+
+    let mut resized = vec![0u32; width * height];
+    for y in 0..rows {
+        resized[y * width..][..columns].copy_from_slice(&buffer[y * old_width..][..columns]);
+    }
+    *buffer = resized;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resize_keeps_pixels() {
+        //2x2 grown to 3x3.
+        let mut buffer = vec![1, 2, 3, 4];
+        resize_buffer(
+            &mut buffer,
+            PhysicalSize::new(2, 2),
+            PhysicalSize::new(3, 3),
+        );
+        assert_eq!(buffer, [1, 2, 0, 3, 4, 0, 0, 0, 0]);
+
+        //Shrunk back to 1x2.
+        resize_buffer(
+            &mut buffer,
+            PhysicalSize::new(3, 3),
+            PhysicalSize::new(1, 2),
+        );
+        assert_eq!(buffer, [1, 3]);
+    }
+
+    #[test]
+    fn resize_mismatched_buffer() {
+        let mut buffer = vec![1, 2, 3];
+        resize_buffer(
+            &mut buffer,
+            PhysicalSize::new(2, 2),
+            PhysicalSize::new(2, 2),
+        );
+        assert_eq!(buffer, [1, 2, 0, 0]);
+
+        let mut buffer = Vec::new();
+        resize_buffer(
+            &mut buffer,
+            PhysicalSize::new(0, 0),
+            PhysicalSize::new(1, 1),
+        );
+        assert_eq!(buffer, [0]);
     }
 }